@@ -0,0 +1,74 @@
+//! Benchmarks for parsing and solving both parts of every day.
+//!
+//! The bundled inputs for every year are read from the working directory, or from `AOC_INPUT_DIR`
+//! if set; days without an input file are skipped. Set `AOC_BENCH_SCALES` to a comma-separated list
+//! of scale factors (such as `2,8`) to also benchmark generated inputs for the days whose cost
+//! grows with the size of the input.
+
+use std::env;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc::input::{InputSource, INPUT_DIR_VAR};
+use aoc::solution::{Day, Part};
+use aoc::{find_day, YEARS};
+
+mod synthetic;
+
+fn bench_input(c: &mut Criterion, name: &str, day: &Day, lines: &[String]) {
+    let input = match day.solution.parse_input(lines) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Skipping {name}: {e:#}");
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| day.solution.parse_input(black_box(lines)))
+    });
+    for part in Part::ALL {
+        group.bench_function(format!("part{part}"), |b| {
+            b.iter(|| day.solution.run_part(black_box(&input), part))
+        });
+    }
+    group.finish();
+}
+
+fn bundled_inputs(c: &mut Criterion) {
+    let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    for year in YEARS {
+        for day in year.days {
+            let name = format!("{}/day{:02}", year.number, day.number);
+            let source = InputSource::for_day(year.number, day.number, input_dir.as_deref());
+            match source.and_then(|source| source.read_lines()) {
+                Ok(lines) => bench_input(c, &name, day, &lines),
+                Err(e) => eprintln!("Skipping {name}: {e:#}"),
+            }
+        }
+    }
+}
+
+fn synthetic_inputs(c: &mut Criterion) {
+    let Ok(scales) = env::var("AOC_BENCH_SCALES") else {
+        return;
+    };
+    for scale in scales.split(',') {
+        let scale: usize = scale
+            .trim()
+            .parse()
+            .expect("AOC_BENCH_SCALES should be a comma-separated list of integers");
+        for &(year, number, generate) in synthetic::GENERATORS {
+            let day = find_day(year, number).expect("generated days are registered");
+            let name = format!("{year}/day{number:02}/synthetic x{scale}");
+            bench_input(c, &name, day, &generate(scale));
+        }
+    }
+}
+
+criterion_group!(benches, bundled_inputs, synthetic_inputs);
+criterion_main!(benches);
@@ -0,0 +1,108 @@
+//! Generated inputs that grow with a scale factor, for the days whose cost depends on input size.
+//!
+//! Scale 1 is roughly the size of a real puzzle input.
+
+use aoc::y2022::day14::MAX_COORDINATE;
+
+/// Generates the lines of an input at the given scale.
+pub type Generator = fn(usize) -> Vec<String>;
+
+/// The generator for each day, by year and day number.
+pub const GENERATORS: &[(u16, u8, Generator)] = &[
+    (2022, 7, day07),
+    (2022, 8, day08),
+    (2022, 14, day14),
+    (2022, 15, day15),
+];
+
+/// A small deterministic xorshift generator, so that every run benchmarks the same input.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// A binary tree of `200 * scale` directories with three files each, totalling about 50MB so that
+/// a directory must still be freed.
+fn day07(scale: usize) -> Vec<String> {
+    fn visit(dir: usize, num_dirs: usize, file_size: usize, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_string());
+        let children = [dir * 2 + 1, dir * 2 + 2];
+        for child in children.iter().filter(|c| **c < num_dirs) {
+            lines.push(format!("dir d{child}"));
+        }
+        for file in 0..3 {
+            lines.push(format!("{file_size} f{dir}_{file}.txt"));
+        }
+        for child in children.iter().filter(|c| **c < num_dirs) {
+            lines.push(format!("$ cd d{child}"));
+            visit(*child, num_dirs, file_size, lines);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+
+    let num_dirs = 200 * scale;
+    let file_size = 50_000_000 / (num_dirs * 3);
+    let mut lines = vec!["$ cd /".to_string()];
+    visit(0, num_dirs, file_size, &mut lines);
+    lines
+}
+
+/// A random square forest `99 * scale` trees across.
+fn day08(scale: usize) -> Vec<String> {
+    let mut rng = Rng(8);
+    let size = 99 * scale;
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.below(10) as u8))
+                .collect()
+        })
+        .collect()
+}
+
+/// Random ledges and walls of rock, reaching `50 * scale` deep (but no deeper than the
+/// `MAX_COORDINATE` that day 14 allows), spread as far either side of the sand source as they
+/// are deep while x stays positive.
+fn day14(scale: usize) -> Vec<String> {
+    let mut rng = Rng(14);
+    let depth = (50 * scale).min(MAX_COORDINATE);
+    let spread = depth.min(490);
+    (0..40 * scale)
+        .map(|_| {
+            let x = 500 - spread + rng.below(2 * spread);
+            let y = 5 + rng.below(depth - 5);
+            let width = 2 + rng.below(8);
+            let height = 1 + rng.below(6).min(depth - 1 - y);
+            format!(
+                "{x},{y} -> {},{y} -> {},{}",
+                x + width,
+                x + width,
+                y + height
+            )
+        })
+        .collect()
+}
+
+/// A lattice of `(5 * scale + 1)²` sensors whose ranges overlap to cover the whole search area,
+/// so that `find_gap` has to scan every row.
+fn day15(scale: usize) -> Vec<String> {
+    let per_side = 5 * scale;
+    let spacing = 4_000_000 / per_side;
+    let mut lines = vec![];
+    for i in 0..=per_side {
+        for j in 0..=per_side {
+            let (x, y) = (i * spacing, j * spacing);
+            lines.push(format!(
+                "Sensor at x={x}, y={y}: closest beacon is at x={}, y={y}",
+                x + spacing
+            ));
+        }
+    }
+    lines
+}
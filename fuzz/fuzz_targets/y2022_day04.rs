@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc::{find_day, runner::solve_text, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = solve_text(find_day(2022, 4).unwrap(), text, &Part::ALL, true);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc::{find_day, runner::solve_text, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        // Part two always runs ten thousand rounds, so only part one is fuzzed.
+        let _ = solve_text(find_day(2022, 11).unwrap(), text, &[Part::One], false);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc::{find_day, runner::solve_text};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        // The sand fills a cave as large as the rock coordinates allow, so only the parsing is bounded.
+        let _ = solve_text(find_day(2022, 14).unwrap(), text, &[], false);
+    }
+});
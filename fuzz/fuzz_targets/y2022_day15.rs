@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc::{find_day, runner::solve_text};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        // Both parts scan rows as wide as the coordinates in the input; the drawing has a fixed size.
+        let _ = solve_text(find_day(2022, 15).unwrap(), text, &[], true);
+    }
+});
@@ -0,0 +1,212 @@
+//! The error raised when a puzzle input cannot be read, saying where and why.
+
+use std::fmt;
+
+/// What was wrong with the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A direction other than `U`, `D`, `L` or `R`.
+    BadDirection,
+    /// Something that should be a number but is not, or is too big.
+    BadNumber,
+    /// An instruction or command that is not recognised.
+    UnknownInstruction,
+    /// A character that does not belong on a map.
+    BadSymbol,
+    /// Text that does not fit the expected layout.
+    Unexpected,
+    /// Something the input should have but does not.
+    Missing(&'static str),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::BadDirection => write!(f, "bad direction"),
+            ErrorKind::BadNumber => write!(f, "bad number"),
+            ErrorKind::UnknownInstruction => write!(f, "unknown instruction"),
+            ErrorKind::BadSymbol => write!(f, "unexpected symbol"),
+            ErrorKind::Unexpected => write!(f, "unexpected text"),
+            ErrorKind::Missing(what) => write!(f, "missing {what}"),
+        }
+    }
+}
+
+/// A problem with a puzzle input. The parsers fill in the position and text, and the runner
+/// fills in the day and file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub file: Option<String>,
+    /// The line number, counting from 1.
+    pub line: Option<usize>,
+    /// The column in characters, counting from 1.
+    pub column: Option<usize>,
+    /// The text that could not be read, empty if the line ended too soon.
+    pub text: String,
+    pub kind: ErrorKind,
+    /// The whole of the line the error is on, for showing in a snippet.
+    pub source: Option<String>,
+    /// What the parser was expecting to find.
+    pub hint: Option<&'static str>,
+}
+
+impl ParseError {
+    pub fn new(kind: ErrorKind, text: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            file: None,
+            line: None,
+            column: None,
+            text: text.into(),
+            kind,
+            source: None,
+            hint: None,
+        }
+    }
+
+    /// Something missing from the input as a whole rather than from any one line.
+    pub fn missing(what: &'static str) -> Self {
+        ParseError::new(ErrorKind::Missing(what), "")
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_hint(mut self, hint: &'static str) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Say which day and input file the error is in, if not already known.
+    pub fn in_input(&mut self, day: u8, file: &dyn fmt::Display) {
+        self.day.get_or_insert(day);
+        self.file.get_or_insert_with(|| file.to_string());
+    }
+
+    /// Where the error is, as `file:line:column`, leaving out whatever is not known.
+    pub fn location(&self) -> String {
+        let mut location = self.file.clone().unwrap_or_else(|| "<input>".to_string());
+        if let Some(line) = self.line {
+            location += &format!(":{line}");
+            if let Some(column) = self.column {
+                location += &format!(":{column}");
+            }
+        }
+        location
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location(), self.kind)?;
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, " in day {day}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Describe `err` for the command line. A parse error anywhere in the chain is shown like a
+/// compiler error, with the offending line and a caret under the text that could not be read;
+/// anything else is shown as the chain of causes.
+pub fn report(err: &anyhow::Error) -> String {
+    let Some(e) = err.downcast_ref::<ParseError>() else {
+        return format!("error: {err:#}");
+    };
+    let mut report = format!("error: {}", e.kind);
+    if !e.text.is_empty() {
+        report += &format!(" {:?}", e.text);
+    }
+
+    let gutter = e.line.map_or(1, |line| line.to_string().len());
+    let pad = " ".repeat(gutter);
+    report += &format!("\n{pad}--> {}", e.location());
+    if let Some(day) = e.day {
+        report += &format!(" (day {day})");
+    }
+    match (e.line, &e.source) {
+        (Some(line), Some(source)) => {
+            report += &format!("\n{pad} |\n{line} | {source}\n{pad} |");
+            if let Some(column) = e.column {
+                let width = e.text.chars().count().max(1);
+                report += &format!(" {}{}", " ".repeat(column - 1), "^".repeat(width));
+                if let Some(hint) = e.hint {
+                    report += &format!(" {hint}");
+                }
+            } else if let Some(hint) = e.hint {
+                report += &format!("\n{pad} = hint: {hint}");
+            }
+        }
+        _ => {
+            if let Some(hint) = e.hint {
+                report += &format!("\n{pad} = hint: {hint}");
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    #[test]
+    fn test_parse_error() {
+        let mut err = ParseError::new(ErrorKind::BadNumber, "1x")
+            .at_line(6)
+            .at_column(8);
+        assert_eq!(err.to_string(), r#"<input>:6:8: bad number "1x""#);
+        err.in_input(5, &"day05.txt");
+        assert_eq!(
+            err.to_string(),
+            r#"day05.txt:6:8: bad number "1x" in day 5"#
+        );
+        assert_eq!(
+            ParseError::missing("list of moves").to_string(),
+            "<input>: missing list of moves"
+        );
+
+        let err = err
+            .at_column(13)
+            .with_source("move 1 from 1x to 3")
+            .with_hint("expected the stack to move from");
+        let wrapped = Err::<(), _>(err).context("Parsing input").unwrap_err();
+        assert_eq!(
+            report(&wrapped),
+            [
+                r#"error: bad number "1x""#,
+                " --> day05.txt:6:13 (day 5)",
+                "  |",
+                "6 | move 1 from 1x to 3",
+                "  |             ^^ expected the stack to move from",
+            ]
+            .join("\n")
+        );
+        let missing = ParseError::missing("list of moves")
+            .with_hint("leave a blank line after the drawing of the stacks");
+        assert_eq!(
+            report(&missing.into()),
+            "error: missing list of moves\n --> <input>\n  = hint: leave a blank line after the drawing of the stacks"
+        );
+        let other = anyhow::anyhow!("No such file").context("Reading input");
+        assert_eq!(report(&other), "error: Reading input: No such file");
+    }
+}
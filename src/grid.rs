@@ -0,0 +1,246 @@
+//! A rectangular grid of cells, as used by the days whose input is a map.
+
+use std::ops::{Index, IndexMut};
+
+use ndarray::{Array2, ArrayView1, Axis};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::point::{Direction, Point};
+
+/// A position in a grid, where `x` is the column and `y` the row.
+pub type Pos = Point<usize>;
+
+/// A grid of cells indexed by position, with row 0 at the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    /// Read a character map, turning each character into a cell with `cell`, which is also told
+    /// where the character is so that it can note down markers such as a start position. A
+    /// character that `cell` returns `None` for is an error.
+    pub fn parse<I, F>(lines: I, mut cell: F) -> Result<Self, ParseError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        F: FnMut(Pos, char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut cols = None;
+        let mut rows = 0;
+        for (row, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let start = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                if cols.is_some_and(|cols| col >= cols) {
+                    let extra: String = line.chars().skip(col).collect();
+                    return Err(ParseError::new(ErrorKind::Unexpected, extra)
+                        .at_line(row + 1)
+                        .at_column(col + 1)
+                        .with_source(line)
+                        .with_hint("expected every row to be the same length"));
+                }
+                let value = cell(Point::new(col, row), c).ok_or_else(|| {
+                    ParseError::new(ErrorKind::BadSymbol, c)
+                        .at_line(row + 1)
+                        .at_column(col + 1)
+                        .with_source(line)
+                })?;
+                cells.push(value);
+            }
+            let width = cells.len() - start;
+            match cols {
+                None => cols = Some(width),
+                Some(cols) if width < cols => {
+                    return Err(ParseError::missing("cells")
+                        .at_line(row + 1)
+                        .at_column(width + 1)
+                        .with_source(line)
+                        .with_hint("expected every row to be the same length"))
+                }
+                _ => {}
+            }
+            rows += 1;
+        }
+        let cols = cols.ok_or(ParseError::missing("map"))?;
+        Ok(Grid {
+            cells: Array2::from_shape_vec((rows, cols), cells)
+                .expect("every row has the same number of cells"),
+        })
+    }
+
+    /// The number of rows in the grid.
+    pub fn rows(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    /// The number of columns in the grid.
+    pub fn cols(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    /// Whether `pos` is inside the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.y < self.rows() && pos.x < self.cols()
+    }
+
+    /// The cell at `pos`, or `None` if it is outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get((pos.y, pos.x))
+    }
+
+    /// The cell at `pos` for changing, or `None` if it is outside the grid.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut((pos.y, pos.x))
+    }
+
+    /// The position one `step` away from `pos`, if that is still inside the grid.
+    pub fn offset(&self, pos: Pos, step: Point<isize>) -> Option<Pos> {
+        let next = Point::new(
+            pos.x.checked_add_signed(step.x)?,
+            pos.y.checked_add_signed(step.y)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d.step()))
+    }
+
+    /// All the neighbours of `pos`, including diagonals, that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Point::new(0, 0)
+            .neighbours8()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The positions reached by repeatedly taking `step` from `pos`, not including `pos` itself,
+    /// until the edge of the grid.
+    pub fn ray(&self, pos: Pos, step: Point<isize>) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(Some(pos), move |&p| self.offset(p, step)).skip(1)
+    }
+
+    /// The cells of one row, from left to right.
+    pub fn row(&self, row: usize) -> ArrayView1<'_, T> {
+        self.cells.index_axis(Axis(0), row)
+    }
+
+    /// The cells of one column, from top to bottom.
+    pub fn col(&self, col: usize) -> ArrayView1<'_, T> {
+        self.cells.index_axis(Axis(1), col)
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells
+            .indexed_iter()
+            .map(|((row, col), cell)| (Point::new(col, row), cell))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.map(f),
+        }
+    }
+
+    /// Draw the grid as text, one line per row, using `cell` to pick each cell's character.
+    pub fn render<F>(&self, mut cell: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        self.cells
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of `rows` by `cols` cells all set to `value`.
+    pub fn from_elem(rows: usize, cols: usize, value: T) -> Self {
+        Grid {
+            cells: Array2::from_elem((rows, cols), value),
+        }
+    }
+
+    /// Set every cell of `row` to `value`.
+    pub fn fill_row(&mut self, row: usize, value: T) {
+        self.cells.index_axis_mut(Axis(0), row).fill(value);
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.cells[(pos.y, pos.x)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        &mut self.cells[(pos.y, pos.x)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_grid() -> Result<(), ParseError> {
+        let mut start = None;
+        let grid = Grid::parse(["ab.", "S.c"], |pos, c| {
+            if c == 'S' {
+                start = Some(pos);
+            }
+            Some(c)
+        })?;
+        assert_eq!(start, Some(Point::new(0, 1)));
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.row(1).to_vec(), ['S', '.', 'c']);
+        assert_eq!(grid.col(2).to_vec(), ['.', 'c']);
+
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+        assert_eq!(
+            grid.ray(Point::new(0, 1), Direction::Right.step())
+                .collect::<Vec<_>>(),
+            [Point::new(1, 1), Point::new(2, 1)]
+        );
+        assert_eq!(grid.ray(Point::new(0, 0), Direction::Up.step()).count(), 0);
+        assert_eq!(grid.render(|c| *c), "ab.\nS.c");
+
+        assert_eq!(
+            Grid::parse(["ab", "abc"], |_, c| Some(c)),
+            Err(ParseError::new(ErrorKind::Unexpected, "c")
+                .at_line(2)
+                .at_column(3)
+                .with_source("abc")
+                .with_hint("expected every row to be the same length"))
+        );
+        assert_eq!(
+            Grid::parse(["ab", "#b"], |_, c| c.is_alphabetic().then_some(c)),
+            Err(ParseError::new(ErrorKind::BadSymbol, "#")
+                .at_line(2)
+                .at_column(1)
+                .with_source("#b"))
+        );
+        assert!(Grid::parse(Vec::<&str>::new(), |_, c| Some(c)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Reading puzzle input from files or standard input.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::store::{Status, Store};
+
+/// The environment variable naming a directory that holds the `<year>/dayNN.txt` input files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The year whose inputs may also be loose `dayNN.txt` files, from before inputs were kept in a
+/// directory for each year.
+pub const LOOSE_INPUT_YEAR: u16 = 2022;
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interpret a path given on the command line, where `-` means standard input.
+    pub fn from_arg(path: &Path) -> Self {
+        if path == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(path.to_path_buf())
+        }
+    }
+
+    /// The usual input for `day` of `year`: the copy in the input store in `dir`, or in the
+    /// working directory if not given, warning if it has changed since it was imported. Without
+    /// a stored copy, the file found by [`InputSource::in_dir`] is used.
+    pub fn for_day(year: u16, day: u8, dir: Option<&Path>) -> Result<Self> {
+        let store = Store::in_dir(dir)?;
+        let status = store.status(year, day)?;
+        if status == Status::Absent {
+            return Ok(InputSource::in_dir(year, day, dir));
+        }
+        let source = InputSource::File(store.path(year, day));
+        if let Status::Changed { .. } = status {
+            eprintln!("warning: input {source} for day {day} of {year} has {status}");
+        }
+        Ok(source)
+    }
+
+    /// The input file for `day` of `year` outside the store: `<year>/dayNN.txt` in `dir`, or in
+    /// the working directory if not given. For [`LOOSE_INPUT_YEAR`], a loose `dayNN.txt` is used
+    /// if there is no file in the year's directory.
+    pub fn in_dir(year: u16, day: u8, dir: Option<&Path>) -> Self {
+        let dir = dir.unwrap_or(Path::new(""));
+        let file_name = format!("day{day:02}.txt");
+        let path = dir.join(year.to_string()).join(&file_name);
+        if year == LOOSE_INPUT_YEAR && !path.exists() {
+            return InputSource::File(dir.join(file_name));
+        }
+        InputSource::File(path)
+    }
+
+    /// Whether there is anything to read; standard input is always assumed to be there.
+    pub fn exists(&self) -> bool {
+        match self {
+            InputSource::File(path) => path.exists(),
+            InputSource::Stdin => true,
+        }
+    }
+
+    /// Open the input, ready to read it line by line.
+    pub fn lines(&self) -> Result<Lines<Box<dyn BufRead>>> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::File(path) => {
+                let file = File::open(path)
+                    .with_context(|| format!("Opening input file {}", path.display()))?;
+                Box::new(BufReader::new(file))
+            }
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+        };
+        Ok(Lines::new(reader, self.to_string()))
+    }
+
+    /// Read every line of the input, failing on the first line that cannot be read.
+    pub fn read_lines(&self) -> Result<Vec<String>> {
+        self.lines()?.collect()
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// The lines of an input, where a line that cannot be read (for example because it is not valid
+/// UTF-8) is an error naming the input and line number rather than being skipped.
+pub struct Lines<R> {
+    lines: io::Lines<R>,
+    name: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    /// Read lines from `reader`, calling it `name` in any errors.
+    pub fn new(reader: R, name: String) -> Self {
+        Lines {
+            lines: reader.lines(),
+            name,
+            line_number: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        self.line_number += 1;
+        Some(line.with_context(|| format!("Reading line {} of {}", self.line_number, self.name)))
+    }
+}
+
+/// Read one of the example inputs kept in the `fixtures` directory.
+#[cfg(test)]
+pub(crate) fn fixture(year: u16, day: u8, name: &str) -> Result<Vec<String>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(year.to_string())
+        .join(format!("day{day:02}"))
+        .join(format!("{name}.txt"));
+    InputSource::File(path).read_lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_input_source() {
+        assert_eq!(InputSource::from_arg(Path::new("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Path::new("mine.txt")),
+            InputSource::File(PathBuf::from("mine.txt"))
+        );
+        assert_eq!(
+            InputSource::in_dir(2023, 3, None),
+            InputSource::File(PathBuf::from("2023/day03.txt"))
+        );
+        assert_eq!(
+            InputSource::in_dir(2022, 12, Some(Path::new("inputs"))),
+            InputSource::File(PathBuf::from("inputs/day12.txt"))
+        );
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn test_lines() -> Result<()> {
+        let lines = Lines::new(&b"1000\n2000\n\n3000"[..], "good.txt".to_string());
+        assert_eq!(
+            lines.collect::<Result<Vec<_>>>()?,
+            ["1000", "2000", "", "3000"]
+        );
+
+        let mut lines = Lines::new(&b"1000\n20\xff0\n3000"[..], "bad.txt".to_string());
+        assert_eq!(lines.next().transpose()?, Some("1000".to_string()));
+        let err = lines.next().context("getting second line")?.unwrap_err();
+        assert_eq!(err.to_string(), "Reading line 2 of bad.txt");
+        Ok(())
+    }
+}
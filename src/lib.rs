@@ -0,0 +1,56 @@
+//! Solutions to the [Advent of Code](https://adventofcode.com) puzzles, for every year solved.
+//!
+//! Each `yYYYY` module holds a year's puzzles, with a `dayNN` module for each day holding its
+//! parsers, types and solvers along with a unit struct implementing [`Solution`] that ties them
+//! together. [`YEARS`] lists every year and the days solved in it.
+
+use anyhow::{Context, Result};
+
+pub use solution::{Answer, Part, Solution};
+use solution::{Day, Year};
+
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod runner;
+pub mod scaffold;
+pub mod simulation;
+pub mod solution;
+pub mod store;
+pub mod timing;
+pub mod tui;
+pub mod verify;
+pub mod watch;
+pub mod y2022;
+
+/// Every year with solved days, in order.
+pub static YEARS: &[Year] = &[Year {
+    number: 2022,
+    days: y2022::DAYS,
+}];
+
+/// Look up a year in [`YEARS`].
+pub fn find_year(number: u16) -> Result<&'static Year> {
+    YEARS
+        .iter()
+        .find(|y| y.number == number)
+        .with_context(|| format!("There are no solutions for {number}"))
+}
+
+/// The most recent year in [`YEARS`], which is run when no year is given.
+pub fn latest_year() -> &'static Year {
+    YEARS.last().expect("at least one year is solved")
+}
+
+/// Look up a day of a year in [`YEARS`].
+pub fn find_day(year: u16, number: u8) -> Result<&'static Day> {
+    find_year(year)?
+        .days
+        .iter()
+        .find(|d| d.number == number)
+        .with_context(|| format!("There is no solution for day {number} of {year}"))
+}
@@ -1,50 +1,372 @@
-use anyhow::Result;
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-
-macro_rules! read_lines {
-    ($expression:expr) => {
-        BufReader::new(std::fs::File::open($expression)?)
-            .lines()
-            .filter(|l| l.is_ok())
-            .map(|l| l.unwrap())
-    };
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+
+use aoc::error::report;
+use aoc::input::{InputSource, INPUT_DIR_VAR};
+use aoc::output::{Format, Output};
+use aoc::runner::{render_day, run_day, run_days};
+use aoc::scaffold::new_day;
+use aoc::simulation::{record_day, replay_day, Trace};
+use aoc::solution::{Day, Part};
+use aoc::store::Store;
+use aoc::timing::Timings;
+use aoc::verify::{verify_day, AnswerSet, Outcome, ANSWERS_FILE};
+use aoc::watch::{compare, InputWatcher, POLL_INTERVAL};
+use aoc::{find_day, find_year, latest_year};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The year of the puzzles [default: the latest year solved]
+    #[arg(long, short, global = true, env = "AOC_YEAR")]
+    year: Option<u16>,
+    /// Directory to look for the <year>/dayNN.txt input files in
+    #[arg(long, global = true, env = INPUT_DIR_VAR)]
+    input_dir: Option<PathBuf>,
+    /// How to write out the answers
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Print a summary of how long each day took to stderr once finished
+    #[arg(long, global = true)]
+    time: bool,
+    /// How many days and parts to run at once [default: the number of available cores]
+    #[arg(long, short, global = true)]
+    jobs: Option<NonZeroUsize>,
 }
 
-pub(crate) use read_lines;
-
-fn main() -> Result<()> {
-    println!("Day  1: {}", day01::day1()?);
-    println!("Day  2: {}", day02::day2()?);
-    println!("Day  3: {:?}", day03::day3()?);
-    println!("Day  4: {}", day04::day4()?);
-    println!("Day  5: {}", day05::day5()?);
-    println!("Day  6: {:?}", day06::day6()?);
-    println!("Day  7: {:?}", day07::day7()?);
-    println!("Day  8: {:?}", day08::day8()?);
-    println!("Day  9: {:?}", day09::day9()?);
-    let (d10a, d10b) = day10::day10()?;
-    println!("Day 10: {}", d10a);
-    println!("{}", d10b);
-    println!("Day 11: {:?}", day11::day11()?);
-    println!("Day 12: {:?}", day12::day12()?);
-    println!("Day 13: {:?}", day13::day13()?);
-    println!("Day 14: {:?}", day14::day14()?);
-    println!("Day 15: {:?}", day15::day15()?);
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one day, or for every day
+    Run {
+        /// The day to run
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Only show the answer to this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), conflicts_with = "all")]
+        part: Option<u8>,
+        /// Run every day in turn
+        #[arg(long)]
+        all: bool,
+        /// Read the puzzle input from this file, or from stdin if `-`
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Also draw the puzzle to this file: .txt, .ansi, .ppm, .png or .svg
+        #[arg(long, conflicts_with = "all")]
+        render: Option<PathBuf>,
+        /// How many pixels square to draw each cell in an image
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..), requires = "render")]
+        scale: u32,
+        /// Keep running the day each time its input file is saved, until interrupted
+        #[arg(long, conflicts_with_all = ["all", "render"])]
+        watch: bool,
+    },
+    /// List the days that have solutions
+    List,
+    /// Browse and run the days, and watch their simulations, in an interactive dashboard
+    Tui {
+        /// The part whose simulations to play, until another is chosen with `p`
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Generate the skeleton of a new day and register it
+    New {
+        /// The day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The root of the crate to add the day to
+        #[arg(long, default_value = ".")]
+        crate_dir: PathBuf,
+    },
+    /// Manage the store of puzzle inputs
+    Input {
+        #[command(subcommand)]
+        command: InputCommand,
+    },
+    /// Record a simulation's state as it runs, or check it against a recorded trace
+    Trace {
+        #[command(subcommand)]
+        command: TraceCommand,
+    },
+    /// Check every day's answers against the answers.toml kept with each set of inputs
+    Verify {
+        /// Directories holding dayNN.txt inputs and an answers.toml [default: the input directory]
+        sets: Vec<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// Copy a puzzle input into the store and record its hash
+    Import {
+        /// The day the input is for
+        day: u8,
+        /// The file to import
+        file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum TraceCommand {
+    /// Run a day's simulation, writing its state to a trace file as it goes
+    Record {
+        /// The day to simulate: 9, 10, 11 or 14
+        day: u8,
+        /// The trace file to write
+        file: PathBuf,
+        /// The part whose simulation to run
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Write the state after every this many steps
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+        /// Read the puzzle input from this file, or from stdin if `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Run the simulation a trace was recorded from and report the first step where they differ
+    Replay {
+        /// The trace file to check
+        file: PathBuf,
+        /// Read the puzzle input from this file, or from stdin if `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let jobs = cli
+        .jobs
+        .or_else(|| std::thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+    let result = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(anyhow::Error::from)
+        .and_then(|pool| pool.install(|| run(cli)));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", report(&e));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let year = cli.year.unwrap_or(latest_year().number);
+    let input_dir = cli.input_dir.as_deref();
+    let mut output = Output::new(cli.format, year, io::stdout().lock());
+    let mut timings = Timings::new();
+    let start = Instant::now();
+
+    match cli.command.unwrap_or(Command::Run {
+        day: None,
+        part: None,
+        all: true,
+        input: None,
+        render: None,
+        scale: 4,
+        watch: false,
+    }) {
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+            render,
+            scale,
+            watch,
+            ..
+        } => {
+            let source = match input {
+                Some(path) => InputSource::from_arg(&path),
+                None => InputSource::for_day(year, day, input_dir)?,
+            };
+            let parts = match part {
+                Some(part) => vec![Part::try_from(part)?],
+                None => Part::ALL.to_vec(),
+            };
+            let day = find_day(year, day)?;
+            if watch {
+                return watch_day(day, &parts, &source);
+            }
+            let result = run_day(day, &parts, &source)?;
+            for part in &result.parts {
+                output.write(part)?;
+            }
+            timings.record(&result);
+            if let Some(path) = render {
+                render_day(day, &source)?.save(&path, scale as usize)?;
+            }
+        }
+        Command::Run { day: None, .. } => {
+            let days = find_year(year)?
+                .days
+                .iter()
+                .map(|day| Ok((day, InputSource::for_day(year, day.number, input_dir)?)))
+                .collect::<Result<Vec<_>>>()?;
+            let mut failures = 0;
+            for result in run_days(&days, &Part::ALL) {
+                let result = match result {
+                    Ok(result) => result,
+                    Err(e) => {
+                        eprintln!("{}", report(&e));
+                        failures += 1;
+                        continue;
+                    }
+                };
+                for part in &result.parts {
+                    output.write(part)?;
+                }
+                timings.record(&result);
+            }
+            if failures > 0 {
+                bail!("{failures} of the {} days failed", days.len());
+            }
+        }
+        Command::Tui { part } => {
+            let days = find_year(year)?
+                .days
+                .iter()
+                .map(|day| Ok((day, InputSource::for_day(year, day.number, input_dir)?)))
+                .collect::<Result<Vec<_>>>()?;
+            aoc::tui::run(year, days, part.try_into()?)?;
+        }
+        Command::List => {
+            for day in find_year(year)?.days {
+                println!("Day {:>2}", day.number);
+            }
+        }
+        Command::New { day, crate_dir } => {
+            let input_dir = input_dir.unwrap_or(Path::new("."));
+            for path in new_day(&crate_dir, input_dir, year, day)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Input {
+            command: InputCommand::Import { day, file },
+        } => {
+            find_day(year, day)?;
+            let mut store = Store::in_dir(input_dir)?;
+            let entry = store.import(year, day, &file)?;
+            println!(
+                "Imported day {day} of {year} to {} (sha256 {})",
+                store.path(year, day).display(),
+                entry.sha256
+            );
+        }
+        Command::Trace {
+            command:
+                TraceCommand::Record {
+                    day,
+                    file,
+                    part,
+                    every,
+                    input,
+                },
+        } => {
+            let source = match input {
+                Some(path) => InputSource::from_arg(&path),
+                None => InputSource::for_day(year, day, input_dir)?,
+            };
+            let writer = File::create(&file)
+                .with_context(|| format!("Creating trace {}", file.display()))?;
+            let steps = record_day(
+                year,
+                day,
+                part.try_into()?,
+                &source.read_lines()?,
+                every,
+                BufWriter::new(writer),
+            )?;
+            println!("Recorded {steps} steps to {}", file.display());
+        }
+        Command::Trace {
+            command: TraceCommand::Replay { file, input },
+        } => {
+            let reader =
+                File::open(&file).with_context(|| format!("Opening trace {}", file.display()))?;
+            let trace = Trace::open(BufReader::new(reader))?;
+            let source = match input {
+                Some(path) => InputSource::from_arg(&path),
+                None => InputSource::for_day(trace.year, trace.day, input_dir)?,
+            };
+            match replay_day(trace, &source.read_lines()?)? {
+                None => println!("No divergence from {}", file.display()),
+                Some(divergence) => {
+                    println!("Diverged at step {}", divergence.step);
+                    println!("  expected: {}", divergence.expected);
+                    match divergence.actual {
+                        Some(actual) => println!("  actual:   {actual}"),
+                        None => println!("  actual:   finished before this step"),
+                    }
+                    bail!("The trace diverged at step {}", divergence.step);
+                }
+            }
+        }
+        Command::Verify { mut sets } => {
+            if sets.is_empty() {
+                sets.push(input_dir.unwrap_or(Path::new(".")).to_path_buf());
+            }
+            let mut failures = 0;
+            for set in &sets {
+                if sets.len() > 1 {
+                    println!("{}:", set.display());
+                }
+                let answers = AnswerSet::load(&set.join(ANSWERS_FILE))?;
+                for day in find_year(year)?.days {
+                    let source = InputSource::for_day(year, day.number, Some(set))?;
+                    for check in verify_day(year, day, &source, &answers) {
+                        println!(
+                            "Day {:>2} part {}: {}",
+                            check.day, check.part, check.outcome
+                        );
+                        if matches!(check.outcome, Outcome::Fail(_)) {
+                            failures += 1;
+                        }
+                    }
+                }
+            }
+            if failures > 0 {
+                bail!("Verification failed for {failures} of the parts checked");
+            }
+        }
+    }
+
+    if cli.time {
+        timings.set_wall(start.elapsed());
+        timings.write_summary(io::stderr().lock())?;
+    }
 
     Ok(())
 }
+
+/// Run `day` now and again each time its input file changes, showing each run's answers beside
+/// the last ones. Errors from the solution are reported and the watching goes on.
+fn watch_day(day: &Day, parts: &[Part], source: &InputSource) -> Result<()> {
+    let InputSource::File(path) = source else {
+        bail!("Only an input file can be watched, not {source}");
+    };
+    let mut watcher = InputWatcher::new(path)?;
+    let mut previous = None;
+    loop {
+        match run_day(day, parts, source) {
+            Ok(result) => {
+                print!("{}", compare(previous.as_ref(), &result));
+                previous = Some(result);
+            }
+            Err(e) => eprintln!("{}", report(&e)),
+        }
+        println!("Watching {} for changes...", path.display());
+        watcher.wait(POLL_INTERVAL)?;
+    }
+}
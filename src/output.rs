@@ -0,0 +1,155 @@
+//! Writing answers in a human- or machine-readable format.
+
+use std::io::Write;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::runner::PartResult;
+use crate::solution::Answer;
+
+/// How answers are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One line per part, with multi-line answers on the lines that follow
+    Text,
+    /// One JSON object per line, with the year, day, part, answer and elapsed seconds
+    Json,
+    /// A header row and then one row per part, with the same fields as JSON
+    Csv,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_secs: f64,
+}
+
+impl<'a> Record<'a> {
+    fn new(year: u16, result: &'a PartResult) -> Self {
+        Record {
+            year,
+            day: result.day,
+            part: result.part.into(),
+            answer: &result.answer,
+            elapsed_secs: result.elapsed.as_secs_f64(),
+        }
+    }
+}
+
+/// Writes each result for one year as it arrives, in the chosen format.
+pub struct Output<W> {
+    format: Format,
+    year: u16,
+    writer: W,
+    wrote_header: bool,
+}
+
+impl<W: Write> Output<W> {
+    pub fn new(format: Format, year: u16, writer: W) -> Self {
+        Output {
+            format,
+            year,
+            writer,
+            wrote_header: false,
+        }
+    }
+
+    pub fn write(&mut self, result: &PartResult) -> Result<()> {
+        match self.format {
+            Format::Text => {
+                let (day, part, answer) = (result.day, result.part, &result.answer);
+                let answer = answer.to_string();
+                if answer.contains('\n') {
+                    writeln!(self.writer, "Day {day:>2} part {part}:\n{answer}")?;
+                } else {
+                    writeln!(self.writer, "Day {day:>2} part {part}: {answer}")?;
+                }
+            }
+            Format::Json => {
+                serde_json::to_writer(&mut self.writer, &Record::new(self.year, result))?;
+                writeln!(self.writer)?;
+            }
+            Format::Csv => {
+                if !self.wrote_header {
+                    writeln!(self.writer, "year,day,part,answer,elapsed_secs")?;
+                    self.wrote_header = true;
+                }
+                let record = Record::new(self.year, result);
+                writeln!(
+                    self.writer,
+                    "{},{},{},{},{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer.to_string()),
+                    record.elapsed_secs
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Quote a CSV field if it contains anything that would otherwise break the row.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::solution::Part;
+
+    fn write_all(format: Format, results: &[PartResult]) -> Result<String> {
+        let mut output = Output::new(format, 2022, vec![]);
+        for result in results {
+            output.write(result)?;
+        }
+        Ok(String::from_utf8(output.writer)?)
+    }
+
+    #[test]
+    fn test_output() -> Result<()> {
+        let results = [
+            PartResult {
+                day: 7,
+                part: Part::One,
+                answer: 95437.into(),
+                elapsed: Duration::from_millis(250),
+            },
+            PartResult {
+                day: 10,
+                part: Part::Two,
+                answer: "##..\n#..#".into(),
+                elapsed: Duration::from_millis(1500),
+            },
+        ];
+        assert_eq!(
+            write_all(Format::Text, &results)?,
+            "Day  7 part 1: 95437\nDay 10 part 2:\n##..\n#..#\n"
+        );
+        assert_eq!(
+            write_all(Format::Json, &results)?,
+            concat!(
+                "{\"year\":2022,\"day\":7,\"part\":1,\"answer\":95437,\"elapsed_secs\":0.25}\n",
+                "{\"year\":2022,\"day\":10,\"part\":2,\"answer\":\"##..\\n#..#\",\"elapsed_secs\":1.5}\n"
+            )
+        );
+        assert_eq!(
+            write_all(Format::Csv, &results)?,
+            "year,day,part,answer,elapsed_secs\n2022,7,1,95437,0.25\n2022,10,2,\"##..\n#..#\",1.5\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+//! Shared nom parsers for the pieces that turn up in many puzzle inputs.
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, one_of, space0},
+    combinator::{all_consuming, map, map_res, opt, recognize},
+    error::FromExternalError,
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair},
+    Finish, IResult, Parser,
+};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::point::{Direction, Point};
+
+/// Where and why a parser failed: `input` is what was left of the line when it did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub input: &'a str,
+    pub kind: ErrorKind,
+    pub hint: Option<&'static str>,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Failure<'a> {
+    fn from_error_kind(input: &'a str, kind: nom::error::ErrorKind) -> Self {
+        let kind = match kind {
+            nom::error::ErrorKind::Digit => ErrorKind::BadNumber,
+            _ => ErrorKind::Unexpected,
+        };
+        Failure {
+            input,
+            kind,
+            hint: None,
+        }
+    }
+
+    fn append(_: &'a str, _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    /// Of two alternatives that both failed, report the one that got further.
+    fn or(self, other: Self) -> Self {
+        if other.input.len() < self.input.len() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Failure<'a> {
+    fn from_external_error(input: &'a str, _: nom::error::ErrorKind, _: E) -> Self {
+        Failure {
+            input,
+            kind: ErrorKind::BadNumber,
+            hint: None,
+        }
+    }
+}
+
+/// The result of one of the puzzle input parsers.
+pub type PResult<'a, O> = IResult<&'a str, O, Failure<'a>>;
+
+/// Report any error from `parser` as `kind`, at the point where `parser` started.
+pub fn expect<'a, O, P>(kind: ErrorKind, mut parser: P) -> impl FnMut(&'a str) -> PResult<'a, O>
+where
+    P: Parser<&'a str, O, Failure<'a>>,
+{
+    move |s| {
+        parser.parse(s).map_err(|e| match e {
+            nom::Err::Error(_) => nom::Err::Error(Failure {
+                input: s,
+                kind,
+                hint: None,
+            }),
+            e => e,
+        })
+    }
+}
+
+/// Say what `parser` is looking for, to show with any error from it that does not already have
+/// a more specific hint.
+pub fn hint<'a, O, P>(hint: &'static str, mut parser: P) -> impl FnMut(&'a str) -> PResult<'a, O>
+where
+    P: Parser<&'a str, O, Failure<'a>>,
+{
+    move |s| {
+        parser.parse(s).map_err(|e| {
+            e.map(|mut failure| {
+                failure.hint.get_or_insert(hint);
+                failure
+            })
+        })
+    }
+}
+
+/// An unsigned integer such as `42`.
+pub fn unsigned<T: FromStr>(s: &str) -> PResult<'_, T> {
+    map_res(digit1, str::parse)(s)
+}
+
+/// An integer with an optional minus sign, such as `-7`.
+pub fn signed<T: FromStr>(s: &str) -> PResult<'_, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(s)
+}
+
+/// An inclusive range written `a-b`.
+pub fn range<T: FromStr>(s: &str) -> PResult<'_, RangeInclusive<T>> {
+    map(separated_pair(unsigned, char('-'), unsigned), |(a, b)| {
+        a..=b
+    })(s)
+}
+
+/// A point written as a pair of numbers, `x,y`.
+pub fn pair_xy<T: FromStr>(s: &str) -> PResult<'_, Point<T>> {
+    map(separated_pair(signed, char(','), signed), |(x, y)| {
+        Point::new(x, y)
+    })(s)
+}
+
+/// A point written with its coordinates named, `x=…, y=…`.
+pub fn coordinate<T: FromStr>(s: &str) -> PResult<'_, Point<T>> {
+    map(
+        separated_pair(
+            preceded(tag("x="), signed),
+            tag(", "),
+            preceded(tag("y="), signed),
+        ),
+        |(x, y)| Point::new(x, y),
+    )(s)
+}
+
+/// A path of points joined by arrows, `x,y -> x,y -> …`.
+pub fn path<T: FromStr>(s: &str) -> PResult<'_, Vec<Point<T>>> {
+    separated_list1(tag(" -> "), pair_xy)(s)
+}
+
+/// A direction written as `U`, `D`, `L` or `R`.
+pub fn direction(s: &str) -> PResult<'_, Direction> {
+    expect(
+        ErrorKind::BadDirection,
+        map_res(recognize(one_of("UDLR")), str::parse),
+    )(s)
+}
+
+/// One or more items separated by commas, with optional spaces around each comma.
+pub fn comma_list<'a, O, P>(item: P) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>>
+where
+    P: Parser<&'a str, O, Failure<'a>>,
+{
+    separated_list1(delimited(space0, char(','), space0), item)
+}
+
+/// Run `parser` over the whole of `line`, failing if anything is left over.
+pub fn parse_line<'a, O, P>(parser: P, line: &'a str) -> Result<O, ParseError>
+where
+    P: Parser<&'a str, O, Failure<'a>>,
+{
+    all_consuming(parser)(line)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|Failure { input, kind, hint }| {
+            let column = line[..line.len() - input.len()].chars().count() + 1;
+            let token = input
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .next()
+                .filter(|t| !t.is_empty())
+                .or_else(|| input.get(..input.chars().next()?.len_utf8()))
+                .unwrap_or("");
+            let mut error = ParseError::new(kind, token)
+                .at_column(column)
+                .with_source(line);
+            error.hint = hint;
+            error
+        })
+}
+
+/// Run `parser` over every line.
+pub fn parse_lines<I, O>(lines: I, parser: fn(&str) -> PResult<'_, O>) -> Result<Vec<O>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(parser, line.as_ref()).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// A run of lines that is separated from the rest of the input by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// The line number of the first line in the input, counting from 1.
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Block {
+    /// Run `parser` over the line at `index` within the block.
+    pub fn parse_line<'a, O, P>(&'a self, index: usize, parser: P) -> Result<O, ParseError>
+    where
+        P: Parser<&'a str, O, Failure<'a>>,
+    {
+        let line = self
+            .lines
+            .get(index)
+            .ok_or_else(|| ParseError::missing("line").at_line(self.first_line + index))?;
+        parse_line(parser, line).map_err(|e| e.at_line(self.first_line + index))
+    }
+
+    /// Run `parser` over every line in the block.
+    pub fn parse_lines<O>(&self, parser: fn(&str) -> PResult<'_, O>) -> Result<Vec<O>, ParseError> {
+        (0..self.lines.len())
+            .map(|i| self.parse_line(i, parser))
+            .collect()
+    }
+}
+
+/// Split the input into blocks separated by blank lines, leaving out any empty blocks.
+pub fn blocks<I>(lines: I) -> Vec<Block>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut blocks: Vec<Block> = vec![];
+    let mut in_block = false;
+    for (i, line) in lines.into_iter().enumerate() {
+        match line.as_ref() {
+            "" => in_block = false,
+            line if in_block => blocks.last_mut().into_iter().for_each(|b| {
+                b.lines.push(line.to_string());
+            }),
+            line => {
+                in_block = true;
+                blocks.push(Block {
+                    first_line: i + 1,
+                    lines: vec![line.to_string()],
+                });
+            }
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_parsers() -> Result<(), ParseError> {
+        assert_eq!(parse_line(signed::<i32>, "-12")?, -12);
+        assert_eq!(parse_line(unsigned::<u32>, "12")?, 12);
+        assert!(parse_line(unsigned::<u32>, "-12").is_err());
+        assert_eq!(parse_line(range::<u32>, "2-4")?, 2..=4);
+        assert_eq!(
+            parse_line(coordinate::<i64>, "x=-2, y=15")?,
+            Point::new(-2, 15)
+        );
+        assert_eq!(
+            parse_line(path::<usize>, "498,4 -> 498,6 -> 496,6")?,
+            [Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)]
+        );
+        assert_eq!(parse_line(direction, "U")?, Direction::Up);
+        assert_eq!(
+            parse_line(comma_list(unsigned::<u32>), "79, 98,3")?,
+            [79, 98, 3]
+        );
+
+        assert_eq!(
+            parse_line(range::<u32>, "2-4x"),
+            Err(ParseError::new(ErrorKind::Unexpected, "x")
+                .at_column(4)
+                .with_source("2-4x"))
+        );
+        assert_eq!(
+            parse_line(
+                hint(
+                    "expected a move",
+                    pair(direction, preceded(char(' '), unsigned::<u8>))
+                ),
+                "X 1"
+            ),
+            Err(ParseError::new(ErrorKind::BadDirection, "X")
+                .at_column(1)
+                .with_source("X 1")
+                .with_hint("expected a move"))
+        );
+        assert_eq!(
+            parse_lines(["1", "2", "300"], unsigned::<u8>),
+            Err(ParseError::new(ErrorKind::BadNumber, "300")
+                .at_line(3)
+                .at_column(1)
+                .with_source("300"))
+        );
+
+        let blocks = blocks(["1", "2", "", "", "x"]);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].parse_lines(unsigned::<u32>)?, [1, 2]);
+        assert_eq!(
+            blocks[1].parse_line(0, unsigned::<u32>),
+            Err(ParseError::new(ErrorKind::BadNumber, "x")
+                .at_line(5)
+                .at_column(1)
+                .with_source("x"))
+        );
+        Ok(())
+    }
+}
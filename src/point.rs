@@ -0,0 +1,197 @@
+//! Points and directions on a 2D plane.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// A point, or an offset between two points, with `y` growing downwards as in the puzzle maps.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// A signed number that can be used for distances between points.
+pub trait Signed: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + From<i8> {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+signed!(i32, i64, isize);
+
+impl<T: Signed> Point<T> {
+    /// The number of orthogonal steps between the two points.
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of steps between the two points when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The sign of each coordinate, turning an offset into a single step.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The four orthogonal neighbours.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self + d.step())
+    }
+
+    /// All eight neighbours, including the diagonals.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Point::new(x, y)))
+            .filter(|&step| step != Point::new(0, 0))
+            .map(move |step: Point<i8>| self + Point::new(step.x.into(), step.y.into()))
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub fn step<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        Point::new(x.into(), y.into())
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    /// Read a direction written as `U`, `D`, `L` or `R`.
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => bail!("Unknown direction {s:?}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_point() -> Result<()> {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a * 3, Point::new(-3, 6));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+        assert_eq!(a.neighbours4().count(), 4);
+        assert!(a.neighbours8().all(|n| a.chebyshev(n) == 1));
+        assert_eq!(a.neighbours8().count(), 8);
+
+        assert_eq!("L".parse::<Direction>()?, Direction::Left);
+        assert_eq!(Direction::Up.step::<i64>(), Point::new(0, -1));
+        assert!("X".parse::<Direction>().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+//! Drawing grids and sets of points as text, coloured text or images.
+//!
+//! A [`Picture`] is a grid of small numbers, each naming an [`Ink`] in its [`Palette`]. Days build
+//! one from their state, and it can then be written out in any of the [`ImageFormat`]s.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// How to draw one kind of cell: a colour for images and a character for text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ink {
+    pub rgb: [u8; 3],
+    pub glyph: char,
+}
+
+impl Ink {
+    pub const fn new(rgb: [u8; 3], glyph: char) -> Self {
+        Ink { rgb, glyph }
+    }
+}
+
+/// The inks a picture is drawn with, where cell value `n` uses the `n`th ink. Ink 0 is the
+/// background of a picture drawn from points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    inks: Vec<Ink>,
+}
+
+impl Palette {
+    pub fn new(inks: impl IntoIterator<Item = Ink>) -> Self {
+        Palette {
+            inks: inks.into_iter().collect(),
+        }
+    }
+
+    /// One ink for each of `glyphs`, shading evenly from `from` to `to`.
+    pub fn gradient(from: [u8; 3], to: [u8; 3], glyphs: &str) -> Self {
+        let steps = glyphs.chars().count().saturating_sub(1).max(1) as u32;
+        Palette::new(glyphs.chars().enumerate().map(|(i, glyph)| {
+            let mix = |a: u8, b: u8| {
+                let (a, b, i) = (a as u32, b as u32, i as u32);
+                ((a * (steps - i) + b * i) / steps) as u8
+            };
+            Ink::new(
+                [
+                    mix(from[0], to[0]),
+                    mix(from[1], to[1]),
+                    mix(from[2], to[2]),
+                ],
+                glyph,
+            )
+        }))
+    }
+
+    /// Add another ink after the existing ones.
+    pub fn with(mut self, ink: Ink) -> Self {
+        self.inks.push(ink);
+        self
+    }
+
+    /// The ink for cell value `n`, using the last ink for anything past the end.
+    pub fn ink(&self, n: u8) -> Ink {
+        self.inks
+            .get(n as usize)
+            .or(self.inks.last())
+            .copied()
+            .unwrap_or(Ink::new([0, 0, 0], '?'))
+    }
+}
+
+/// The formats a picture can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Plain text, one character per cell
+    Ascii,
+    /// Text coloured with 24-bit ANSI escape codes
+    Ansi,
+    /// A binary PPM image
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// The format to use for `path`, going by its extension.
+    pub fn for_path(path: &Path) -> Result<Self> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        Ok(match extension.to_ascii_lowercase().as_str() {
+            "txt" => ImageFormat::Ascii,
+            "ansi" => ImageFormat::Ansi,
+            "ppm" => ImageFormat::Ppm,
+            "png" => ImageFormat::Png,
+            "svg" => ImageFormat::Svg,
+            _ => bail!(
+                "Cannot tell how to draw {}: use a .txt, .ansi, .ppm, .png or .svg file",
+                path.display()
+            ),
+        })
+    }
+}
+
+/// The most cells [`Picture::try_from_points`] will draw.
+pub const MAX_CELLS: usize = 1 << 24;
+
+/// A grid of cells to draw, each naming an ink in the palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    cells: Grid<u8>,
+    palette: Palette,
+    /// The point drawn in the top left cell.
+    origin: Point<i64>,
+}
+
+impl Picture {
+    /// Draw each cell of `grid` with the ink picked by `ink`.
+    pub fn from_grid<T, F>(grid: &Grid<T>, palette: Palette, ink: F) -> Self
+    where
+        F: FnMut(&T) -> u8,
+    {
+        Picture {
+            cells: grid.map(ink),
+            palette,
+            origin: Point::new(0, 0),
+        }
+    }
+
+    /// Draw just the area covering `points`, each with the ink given, on a background of ink 0.
+    /// Where a point is given more than once, the last ink given wins.
+    pub fn from_points<I>(points: I, palette: Palette) -> Self
+    where
+        I: IntoIterator<Item = (Point<i64>, u8)>,
+    {
+        let points: Vec<_> = points.into_iter().collect();
+        let min_x = points.iter().map(|(p, _)| p.x).min().unwrap_or(0);
+        let max_x = points.iter().map(|(p, _)| p.x).max().unwrap_or(0);
+        let min_y = points.iter().map(|(p, _)| p.y).min().unwrap_or(0);
+        let max_y = points.iter().map(|(p, _)| p.y).max().unwrap_or(0);
+        let mut cells = Grid::from_elem(
+            (max_y - min_y + 1) as usize,
+            (max_x - min_x + 1) as usize,
+            0,
+        );
+        for (p, ink) in points {
+            cells[Point::new((p.x - min_x) as usize, (p.y - min_y) as usize)] = ink;
+        }
+        Picture {
+            cells,
+            palette,
+            origin: Point::new(min_x, min_y),
+        }
+    }
+
+    /// Like [`Picture::from_points`], but failing rather than drawing more than [`MAX_CELLS`]
+    /// cells, for points that come straight from the input.
+    pub fn try_from_points<I>(points: I, palette: Palette) -> Result<Self>
+    where
+        I: IntoIterator<Item = (Point<i64>, u8)>,
+    {
+        let points: Vec<_> = points.into_iter().collect();
+        let span = |coord: fn(&Point<i64>) -> i64| {
+            let values = points.iter().map(|(p, _)| i128::from(coord(p)));
+            values.clone().max().unwrap_or(0) - values.min().unwrap_or(0) + 1
+        };
+        let (width, height) = (span(|p| p.x), span(|p| p.y));
+        if width * height > MAX_CELLS as i128 {
+            bail!("A picture {width} by {height} cells is too large to draw");
+        }
+        Ok(Picture::from_points(points, palette))
+    }
+
+    /// The number of cells across.
+    pub fn width(&self) -> usize {
+        self.cells.cols()
+    }
+
+    /// The number of cells down.
+    pub fn height(&self) -> usize {
+        self.cells.rows()
+    }
+
+    /// The ink of the cell at `pos`.
+    pub fn ink_at(&self, pos: Point<usize>) -> Ink {
+        self.palette.ink(self.cells.get(pos).copied().unwrap_or(0))
+    }
+
+    /// The cell that `point` is drawn in, as given to [`Picture::from_points`] or as a position
+    /// in the grid given to [`Picture::from_grid`].
+    pub fn cell_at(&self, point: Point<i64>) -> Option<Point<usize>> {
+        let offset = point - self.origin;
+        let cell = Point::new(
+            usize::try_from(offset.x).ok()?,
+            usize::try_from(offset.y).ok()?,
+        );
+        self.cells.contains(cell).then_some(cell)
+    }
+
+    /// Draw over the cells at `points` with `ink`, ignoring any outside the picture.
+    pub fn paint<I>(&mut self, points: I, ink: u8)
+    where
+        I: IntoIterator<Item = Point<usize>>,
+    {
+        for p in points {
+            if let Some(cell) = self.cells.get_mut(p) {
+                *cell = ink;
+            }
+        }
+    }
+
+    /// The picture as plain text.
+    pub fn to_ascii(&self) -> String {
+        self.cells.render(|&n| self.palette.ink(n).glyph)
+    }
+
+    /// The picture as text, with each character coloured in its ink.
+    pub fn to_ansi(&self) -> String {
+        (0..self.height())
+            .map(|y| {
+                let mut line: String = self
+                    .cells
+                    .row(y)
+                    .iter()
+                    .map(|&n| {
+                        let Ink {
+                            rgb: [r, g, b],
+                            glyph,
+                        } = self.palette.ink(n);
+                        format!("\x1b[38;2;{r};{g};{b}m{glyph}")
+                    })
+                    .collect();
+                line += "\x1b[0m";
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The colour of every pixel, row by row, with each cell drawn `scale` pixels square.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+        for y in 0..self.height() {
+            let row: Vec<u8> = self
+                .cells
+                .row(y)
+                .iter()
+                .flat_map(|&n| {
+                    let rgb = self.palette.ink(n).rgb;
+                    std::iter::repeat_n(rgb, scale).flatten()
+                })
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row);
+            }
+        }
+        pixels
+    }
+
+    /// Write the picture as a binary PPM image.
+    pub fn write_ppm<W: Write>(&self, mut writer: W, scale: usize) -> Result<()> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        write!(writer, "P6\n{width} {height}\n255\n")?;
+        writer.write_all(&self.pixels(scale))?;
+        Ok(())
+    }
+
+    /// Write the picture as a PNG image.
+    pub fn write_png<W: Write>(&self, writer: W, scale: usize) -> Result<()> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut encoder = png::Encoder::new(writer, width.try_into()?, height.try_into()?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels(scale))?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// The picture as an SVG image, with each cell drawn `scale` units square.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let fill = |n: u8| {
+            let [r, g, b] = self.palette.ink(n).rgb;
+            format!("#{r:02x}{g:02x}{b:02x}")
+        };
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n\
+             <rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+            fill(0)
+        );
+        // Draw each run of cells with the same ink as one rectangle, leaving out the background.
+        for y in 0..self.height() {
+            let row = self.cells.row(y);
+            let mut x = 0;
+            while x < row.len() {
+                let n = row[x];
+                let run = row.iter().skip(x).take_while(|&&m| m == n).count();
+                if n != 0 {
+                    svg += &format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{scale}\" fill=\"{}\"/>\n",
+                        x * scale,
+                        y * scale,
+                        run * scale,
+                        fill(n)
+                    );
+                }
+                x += run;
+            }
+        }
+        svg += "</svg>\n";
+        svg
+    }
+
+    /// Write the picture in `format`, with `scale` used by the image formats.
+    pub fn write<W: Write>(&self, mut writer: W, format: ImageFormat, scale: usize) -> Result<()> {
+        match format {
+            ImageFormat::Ascii => writeln!(writer, "{}", self.to_ascii())?,
+            ImageFormat::Ansi => writeln!(writer, "{}", self.to_ansi())?,
+            ImageFormat::Ppm => self.write_ppm(writer, scale)?,
+            ImageFormat::Png => self.write_png(writer, scale)?,
+            ImageFormat::Svg => write!(writer, "{}", self.to_svg(scale))?,
+        }
+        Ok(())
+    }
+
+    /// Write the picture to `path`, in the format its extension names.
+    pub fn save(&self, path: &Path, scale: usize) -> Result<()> {
+        let format = ImageFormat::for_path(path)?;
+        let file =
+            File::create(path).with_context(|| format!("Creating image {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        self.write(&mut writer, format, scale)
+            .with_context(|| format!("Writing image {}", path.display()))?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_picture() -> Result<()> {
+        let palette = Palette::new([Ink::new([0, 0, 0], '.'), Ink::new([255, 0, 0], '#')]);
+        let mut picture = Picture::from_points(
+            [(Point::new(-1, 5), 1), (Point::new(1, 6), 1)],
+            palette.clone(),
+        );
+        assert_eq!(picture.to_ascii(), "#..\n..#");
+        assert_eq!(picture.cell_at(Point::new(1, 6)), Some(Point::new(2, 1)));
+        assert_eq!(picture.cell_at(Point::new(-2, 6)), None);
+        let far = [(Point::new(0, 0), 1), (Point::new(1 << 20, 1 << 20), 1)];
+        assert!(Picture::try_from_points(far, palette.clone()).is_err());
+        assert_eq!(picture.ink_at(Point::new(2, 1)).glyph, '#');
+        picture.paint([Point::new(1, 0)], 1);
+        assert_eq!(picture.to_ascii(), "##.\n..#");
+        assert!(picture.to_ansi().starts_with("\x1b[38;2;255;0;0m#"));
+
+        let mut ppm = vec![];
+        picture.write_ppm(&mut ppm, 2)?;
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n\xff\x00\x00"));
+        assert_eq!(ppm.len(), "P6\n6 4\n255\n".len() + 6 * 4 * 3);
+        let mut png = vec![];
+        picture.write_png(&mut png, 1)?;
+        assert!(png.starts_with(b"\x89PNG"));
+        let svg = picture.to_svg(10);
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"20\" height=\"10\" fill=\"#ff0000\"/>"));
+        assert_eq!(svg.matches("<rect").count(), 3);
+
+        let grid = Grid::parse(["09"], |_, c| c.to_digit(10))?;
+        let heights = Picture::from_grid(
+            &grid,
+            Palette::gradient([0; 3], [90; 3], "0123456789"),
+            |&h| h as u8,
+        );
+        assert_eq!(heights.to_ascii(), "09");
+        assert_eq!(heights.palette.ink(9).rgb, [90; 3]);
+
+        assert_eq!(ImageFormat::for_path(Path::new("a.PNG"))?, ImageFormat::Png);
+        assert!(ImageFormat::for_path(Path::new("a.gif")).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Running a day's solution and timing each part.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+
+use crate::error::ParseError;
+use crate::input::InputSource;
+use crate::render::Picture;
+use crate::solution::{Answer, AnyInput, Day, Part};
+
+/// The answer to one part of a day's puzzle, and how long it took to find.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// The results of running a day, with how long it took to parse the input.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Read and parse the input for `day`.
+fn parse_day(day: &Day, source: &InputSource) -> Result<AnyInput> {
+    let lines = source
+        .read_lines()
+        .with_context(|| format!("Reading input for day {}", day.number))?;
+    day.solution
+        .parse_input(&lines)
+        .map_err(|mut e| {
+            if let Some(e) = e.downcast_mut::<ParseError>() {
+                e.in_input(day.number, source);
+            }
+            e
+        })
+        .with_context(|| format!("Parsing input for day {}", day.number))
+}
+
+/// Read and parse the input for `day`, then solve each of `parts`, in parallel when run inside a
+/// thread pool.
+pub fn run_day(day: &Day, parts: &[Part], source: &InputSource) -> Result<DayResult> {
+    let start = Instant::now();
+    let input = parse_day(day, source)?;
+    let parse_elapsed = start.elapsed();
+    let parts = parts
+        .par_iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = day
+                .solution
+                .run_part(&input, part)
+                .with_context(|| format!("Running day {} part {part}", day.number))?;
+            Ok(PartResult {
+                day: day.number,
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(DayResult {
+        day: day.number,
+        parse_elapsed,
+        parts,
+    })
+}
+
+/// Read and parse the input for `day` and draw the puzzle's state.
+pub fn render_day(day: &Day, source: &InputSource) -> Result<Picture> {
+    let input = parse_day(day, source)?;
+    day.solution
+        .render_input(&input)
+        .with_context(|| format!("Drawing day {}", day.number))?
+        .with_context(|| format!("Day {} has nothing to draw", day.number))
+}
+
+/// Parse `text` as the input for `day`, solve `parts` of it and draw it if `render` is set,
+/// stopping at the first error. Used by the fuzz targets, which check that bad input is an error
+/// and never a panic, and which leave out the parts whose work grows with the numbers in the input.
+pub fn solve_text(day: &Day, text: &str, parts: &[Part], render: bool) -> Result<Vec<Answer>> {
+    let lines: Vec<String> = text.lines().map(str::to_string).collect();
+    let input = day.solution.parse_input(&lines)?;
+    let answers = parts
+        .iter()
+        .map(|&part| day.solution.run_part(&input, part))
+        .collect::<Result<_>>()?;
+    if render {
+        day.solution.render_input(&input)?;
+    }
+    Ok(answers)
+}
+
+/// Run each day with its input on the current thread pool, returning the results in the same
+/// order as `days`.
+pub fn run_days(days: &[(&Day, InputSource)], parts: &[Part]) -> Vec<Result<DayResult>> {
+    days.par_iter()
+        .map(|(day, source)| run_day(day, parts, source))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::DAYS;
+
+    #[test]
+    fn test_run_days_in_order() -> Result<()> {
+        let days: Vec<_> = DAYS
+            .iter()
+            .take(4)
+            .map(|day| {
+                let path = format!(
+                    "{}/fixtures/2022/day{:02}/example.txt",
+                    env!("CARGO_MANIFEST_DIR"),
+                    day.number
+                );
+                (day, InputSource::File(path.into()))
+            })
+            .collect();
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build()?;
+        let results = pool.install(|| run_days(&days, &Part::ALL));
+        for (result, (day, _)) in results.into_iter().zip(&days) {
+            let result = result?;
+            assert_eq!(result.day, day.number);
+            assert_eq!(
+                result.parts.iter().map(|p| p.part).collect::<Vec<_>>(),
+                Part::ALL
+            );
+        }
+        Ok(())
+    }
+}
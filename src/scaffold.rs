@@ -0,0 +1,371 @@
+//! Generating the skeleton of a new day and registering it in its year's `DAYS`, adding the year
+//! to [`YEARS`](crate::YEARS) if it is new.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+/// The skeleton module for `day` of `year`, with a parser, unsolved parts and a test of the
+/// example input.
+pub fn module_source(year: u16, day: u8) -> String {
+    format!(
+        r#"use anyhow::{{bail, Context, Result}};
+
+use crate::error::ParseError;
+use crate::parse::{{parse_lines, unsigned}};
+use crate::solution::{{Answer, Solution}};
+
+/// Day {day}: TODO.
+pub struct Day{day:02};
+
+/// TODO: read the puzzle input.
+pub fn parse_input<I>(lines: I) -> Result<Vec<u32>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{{
+    parse_lines(lines, unsigned)
+}}
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<u32>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {{
+        parse_input(lines).context("Reading puzzle input")
+    }}
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer> {{
+        bail!("Part 1 is not solved yet")
+    }}
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {{
+        bail!("Part 2 is not solved yet")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::y{year}::fixture;
+    #[test]
+    fn test_day{day}() -> Result<()> {{
+        let _input = parse_input(fixture({day}, "example")?)?;
+        // TODO: check the answers to the example.
+        Ok(())
+    }}
+}}
+"#
+    )
+}
+
+/// The module for a new `year`, with no days solved yet.
+pub fn year_source(year: u16) -> String {
+    format!(
+        r#"//! Advent of Code {year}.
+
+use crate::solution::Day;
+
+/// The year these puzzles were set.
+pub const YEAR: u16 = {year};
+
+/// Every solved day of {year}, in order.
+pub static DAYS: &[Day] = &[
+];
+
+/// The example input `name` for `day` of this year, for tests.
+#[cfg(test)]
+pub(crate) fn fixture(day: u8, name: &str) -> anyhow::Result<Vec<String>> {{
+    crate::input::fixture(YEAR, day, name)
+}}
+"#
+    )
+}
+
+/// Add `day` to the `mod` declarations and to `DAYS` in the text of a year's `mod.rs`, keeping
+/// both in day order.
+pub fn register(year_module: &str, day: u8) -> Result<String> {
+    let module = format!("pub mod day{day:02};");
+    if year_module.lines().any(|line| line == module) {
+        bail!("Day {day} is already registered");
+    }
+
+    let mut lines: Vec<String> = year_module.lines().map(str::to_string).collect();
+    let day_mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| day_module(&lines[i]).is_some())
+        .collect();
+    match day_mods.last() {
+        Some(&last_mod) => {
+            let at = day_mods
+                .iter()
+                .copied()
+                .find(|&i| day_module(&lines[i]) > Some(day))
+                .unwrap_or(last_mod + 1);
+            lines.insert(at, module);
+        }
+        None => {
+            // The first day of the year goes in its own paragraph before the doc comment on DAYS.
+            let at = lines
+                .iter()
+                .position(|line| line.starts_with("pub static DAYS"))
+                .context("Could not find DAYS in the year's module")?;
+            let at = at.saturating_sub(1);
+            lines.splice(at..at, [module, String::new()]);
+        }
+    }
+
+    let (start, end) = static_list(&lines, "pub static DAYS")?;
+    let at = (start..end)
+        .find(|&i| {
+            lines[i] == "    Day {"
+                && lines[i + 1]
+                    .trim()
+                    .strip_prefix("number: ")
+                    .and_then(|n| n.trim_end_matches(',').parse::<u8>().ok())
+                    .is_some_and(|n| n > day)
+        })
+        .unwrap_or(end);
+    let entry = [
+        "    Day {".to_string(),
+        format!("        number: {day},"),
+        format!("        solution: &day{day:02}::Day{day:02},"),
+        "    },".to_string(),
+    ];
+    lines.splice(at..at, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add `year` to the `mod` declarations and to [`YEARS`](crate::YEARS) in the text of `lib.rs`,
+/// keeping both in order.
+pub fn register_year(lib: &str, year: u16) -> Result<String> {
+    let module = format!("pub mod y{year};");
+    if lib.lines().any(|line| line == module) {
+        bail!("{year} is already registered");
+    }
+
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod "))
+        .collect();
+    let Some(&last_mod) = mods.last() else {
+        bail!("Could not find the modules in lib.rs");
+    };
+    let at = mods
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > module)
+        .unwrap_or(last_mod + 1);
+    lines.insert(at, module);
+
+    expand_single_year(&mut lines);
+    let (start, end) = static_list(&lines, "pub static YEARS")?;
+    let at = (start..end)
+        .find(|&i| {
+            lines[i] == "    Year {"
+                && lines[i + 1]
+                    .trim()
+                    .strip_prefix("number: ")
+                    .and_then(|n| n.trim_end_matches(',').parse::<u16>().ok())
+                    .is_some_and(|n| n > year)
+        })
+        .unwrap_or(end);
+    let entry = [
+        "    Year {".to_string(),
+        format!("        number: {year},"),
+        format!("        days: y{year}::DAYS,"),
+        "    },".to_string(),
+    ];
+    lines.splice(at..at, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Put the entry of a [`YEARS`](crate::YEARS) with only one year on lines of its own, as rustfmt
+/// puts it on the line that starts the slice.
+fn expand_single_year(lines: &mut Vec<String>) {
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.starts_with("pub static YEARS") && line.ends_with("&[Year {"))
+    else {
+        return;
+    };
+    let Some(end) = lines[start..].iter().position(|line| line == "}];") else {
+        return;
+    };
+    let end = start + end;
+    for line in &mut lines[start + 1..end] {
+        line.insert_str(0, "    ");
+    }
+    lines.splice(end..=end, ["    },".to_string(), "];".to_string()]);
+    let declaration = lines[start].trim_end_matches("Year {").to_string();
+    lines.splice(start..=start, [declaration, "    Year {".to_string()]);
+}
+
+/// The first and last lines of the static slice declared on the line starting with `declaration`.
+fn static_list(lines: &[String], declaration: &str) -> Result<(usize, usize)> {
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(declaration))
+        .with_context(|| format!("Could not find `{declaration}`"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .with_context(|| format!("Could not find the end of `{declaration}`"))?;
+    Ok((start, end))
+}
+
+/// The day number of a `pub mod dayNN;` line.
+fn day_module(line: &str) -> Option<u8> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+/// Create the module, an empty example fixture and an empty input for `day` of `year` in the
+/// crate at `crate_dir`, and register the day, adding the year's module first if it is new.
+/// Nothing is overwritten: an existing day is an error, and an existing input is left as it is.
+/// Returns the files created or changed.
+pub fn new_day(crate_dir: &Path, input_dir: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let lib_path = crate_dir.join("src").join("lib.rs");
+    let year_dir = crate_dir.join("src").join(format!("y{year}"));
+    let year_path = year_dir.join("mod.rs");
+    let module_path = year_dir.join(format!("day{day:02}.rs"));
+    let fixture_dir = crate_dir
+        .join("fixtures")
+        .join(year.to_string())
+        .join(format!("day{day:02}"));
+    let fixture_path = fixture_dir.join("example.txt");
+    let input_path = input_dir
+        .join(year.to_string())
+        .join(format!("day{day:02}.txt"));
+
+    if module_path.exists() {
+        bail!(
+            "Day {day} of {year} already exists at {}",
+            module_path.display()
+        );
+    }
+    let mut changed = vec![];
+    let new_year = !year_path.exists();
+    let year_module = if new_year {
+        let lib = fs::read_to_string(&lib_path)
+            .with_context(|| format!("Reading {}", lib_path.display()))?;
+        let lib = register_year(&lib, year)?;
+        fs::create_dir_all(&year_dir)
+            .with_context(|| format!("Creating {}", year_dir.display()))?;
+        fs::write(&lib_path, lib).with_context(|| format!("Writing {}", lib_path.display()))?;
+        changed.push(lib_path);
+        year_source(year)
+    } else {
+        fs::read_to_string(&year_path)
+            .with_context(|| format!("Reading {}", year_path.display()))?
+    };
+    let year_module = register(&year_module, day)?;
+
+    fs::write(&module_path, module_source(year, day))
+        .with_context(|| format!("Writing {}", module_path.display()))?;
+    fs::create_dir_all(&fixture_dir)
+        .with_context(|| format!("Creating {}", fixture_dir.display()))?;
+    fs::write(&fixture_path, "").with_context(|| format!("Writing {}", fixture_path.display()))?;
+    fs::write(&year_path, year_module)
+        .with_context(|| format!("Writing {}", year_path.display()))?;
+    changed.extend([module_path, fixture_path, year_path]);
+    if !input_path.exists() {
+        if let Some(dir) = input_path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Creating {}", dir.display()))?;
+        }
+        fs::write(&input_path, "").with_context(|| format!("Writing {}", input_path.display()))?;
+        changed.push(input_path);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_register() -> Result<()> {
+        let lib = "\
+pub mod day01;
+pub mod day03;
+pub mod error;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &day01::Day01,
+    },
+    Day {
+        number: 3,
+        solution: &day03::Day03,
+    },
+];
+";
+        assert_eq!(
+            register(lib, 2)?,
+            "\
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod error;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &day01::Day01,
+    },
+    Day {
+        number: 2,
+        solution: &day02::Day02,
+    },
+    Day {
+        number: 3,
+        solution: &day03::Day03,
+    },
+];
+"
+        );
+        let registered = register(lib, 16)?;
+        assert!(registered.contains("pub mod day03;\npub mod day16;\npub mod error;"));
+        assert!(registered.ends_with("        solution: &day16::Day16,\n    },\n];\n"));
+        assert!(register(lib, 3).is_err());
+        assert!(module_source(2022, 16).contains("pub struct Day16;"));
+
+        let year = register(&year_source(2023), 1)?;
+        assert!(year.contains("pub const YEAR: u16 = 2023;\n\npub mod day01;\n\n/// Every"));
+        assert!(year.contains("    Day {\n        number: 1,\n        solution: &day01::Day01,"));
+
+        let lib = "\
+pub mod verify;
+pub mod y2022;
+
+pub static YEARS: &[Year] = &[
+    Year {
+        number: 2022,
+        days: y2022::DAYS,
+    },
+];
+";
+        let formatted = "\
+pub static YEARS: &[Year] = &[Year {
+    number: 2022,
+    days: y2022::DAYS,
+}];
+";
+        assert_eq!(
+            register_year(
+                &format!("pub mod verify;\npub mod y2022;\n\n{formatted}"),
+                2023
+            )?,
+            register_year(lib, 2023)?
+        );
+        let registered = register_year(lib, 2023)?;
+        assert!(registered.contains("pub mod y2022;\npub mod y2023;\n"));
+        assert!(registered.ends_with("        days: y2023::DAYS,\n    },\n];\n"));
+        assert!(register_year(&registered, 2023).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+//! Simulations that can be run a step at a time, saved and restored, and traced to a file.
+//!
+//! A trace is JSON with one object per line: a header naming the puzzle and part, and then the
+//! state of the simulation after every so many steps, ending with its final state:
+//!
+//! ```text
+//! {"year":2022,"day":14,"part":1,"every":100}
+//! {"step":0,"state":{…}}
+//! {"step":100,"state":{…}}
+//! ```
+//!
+//! Replaying a trace runs our own simulation alongside it and reports the first step at which the
+//! two disagree, so that a trace written by another solver can be checked against ours.
+
+use std::io::{BufRead, Write};
+
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::find_day;
+use crate::point::Point;
+use crate::render::Picture;
+use crate::solution::Part;
+
+/// A puzzle simulation that can be run one step at a time.
+pub trait Simulation {
+    /// Everything about the simulation that changes as it runs.
+    type Snapshot: Serialize + DeserializeOwned;
+
+    /// Run one step, returning false once the simulation has finished, in which case nothing
+    /// changes.
+    fn step(&mut self) -> Result<bool>;
+    /// The state of the simulation, which can be passed to [`Simulation::restore`] later.
+    fn snapshot(&self) -> Self::Snapshot;
+    /// Go back to a state returned by [`Simulation::snapshot`].
+    fn restore(&mut self, snapshot: &Self::Snapshot) -> Result<()>;
+
+    /// Step until `done` is true of the simulation or it finishes, returning whether `done` was
+    /// met.
+    fn run_until<F>(&mut self, mut done: F) -> Result<bool>
+    where
+        F: FnMut(&Self) -> bool,
+    {
+        loop {
+            if done(self) {
+                return Ok(true);
+            }
+            if !self.step()? {
+                return Ok(false);
+            }
+        }
+    }
+
+    /// Step until the simulation finishes.
+    fn run(&mut self) -> Result<()> {
+        self.run_until(|_| false).map(|_| ())
+    }
+}
+
+/// The object-safe view of a [`Simulation`], with its snapshots as JSON, so that the simulations
+/// of different days can be stepped and traced alike.
+pub trait Traced {
+    /// Run one step, as [`Simulation::step`].
+    fn advance(&mut self) -> Result<bool>;
+    /// The snapshot of the simulation, as JSON.
+    fn state(&self) -> Result<Value>;
+}
+
+impl<S: Simulation> Traced for S {
+    fn advance(&mut self) -> Result<bool> {
+        self.step()
+    }
+
+    fn state(&self) -> Result<Value> {
+        Ok(serde_json::to_value(self.snapshot())?)
+    }
+}
+
+/// A simulation that can be played one step at a time and drawn as it goes.
+pub trait Animation: Traced {
+    /// What is being simulated.
+    fn title(&self) -> String;
+    fn picture(&self) -> Picture;
+    /// A line describing the state, such as a count so far.
+    fn status(&self) -> String;
+    /// The point to keep in view, in the coordinates the picture was drawn from.
+    fn focus(&self) -> Option<Point<i64>> {
+        None
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    year: u16,
+    day: u8,
+    part: u8,
+    every: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    step: u64,
+    state: Value,
+}
+
+/// Run `sim` to the end, writing its state every `every` steps and at the end.
+pub fn record<W: Write>(
+    sim: &mut dyn Traced,
+    year: u16,
+    day: u8,
+    part: Part,
+    every: u64,
+    mut writer: W,
+) -> Result<u64> {
+    let every = every.max(1);
+    let header = Header {
+        year,
+        day,
+        part: part.into(),
+        every,
+    };
+    writeln!(writer, "{}", serde_json::to_string(&header)?)?;
+    let mut write = |step, sim: &dyn Traced| -> Result<()> {
+        let state = sim.state()?;
+        writeln!(
+            writer,
+            "{}",
+            serde_json::to_string(&Record { step, state })?
+        )?;
+        Ok(())
+    };
+    let mut step = 0;
+    write(step, sim)?;
+    while sim.advance()? {
+        step += 1;
+        if step % every == 0 {
+            write(step, sim)?;
+        }
+    }
+    if step % every != 0 {
+        write(step, sim)?;
+    }
+    Ok(step)
+}
+
+/// Where a trace and our own simulation first disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub step: u64,
+    /// The state in the trace.
+    pub expected: Value,
+    /// Our state at the same step, or `None` if our simulation had already finished.
+    pub actual: Option<Value>,
+}
+
+/// The header of a trace and its records, each read and checked as it is needed.
+pub struct Trace<R> {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    lines: std::io::Lines<R>,
+    line_number: usize,
+}
+
+impl<R: BufRead> Trace<R> {
+    pub fn open(reader: R) -> Result<Self> {
+        let mut lines = reader.lines();
+        let header = lines.next().context("The trace is empty")??;
+        let header: Header =
+            serde_json::from_str(&header).context("Reading the header of the trace")?;
+        Ok(Trace {
+            year: header.year,
+            day: header.day,
+            part: header.part.try_into()?,
+            lines,
+            line_number: 1,
+        })
+    }
+
+    /// The next step and state in the trace.
+    fn next_record(&mut self) -> Result<Option<Record>> {
+        let Some(line) = self.lines.next() else {
+            return Ok(None);
+        };
+        self.line_number += 1;
+        let record = serde_json::from_str(&line?)
+            .with_context(|| format!("Reading line {} of the trace", self.line_number))?;
+        Ok(Some(record))
+    }
+
+    /// The state of the simulation at `step`, as recorded in the trace, if it was.
+    pub fn state_at<S: Simulation>(mut self, step: u64) -> Result<Option<S::Snapshot>> {
+        while let Some(record) = self.next_record()? {
+            if record.step == step {
+                return Ok(Some(serde_json::from_value(record.state)?));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Run `sim` alongside the trace, returning the first recorded step whose state differs from
+    /// ours, if any.
+    pub fn replay(mut self, sim: &mut dyn Traced) -> Result<Option<Divergence>> {
+        let mut step = 0;
+        while let Some(record) = self.next_record()? {
+            if record.step < step {
+                bail!(
+                    "Line {} of the trace goes back to step {}",
+                    self.line_number,
+                    record.step
+                );
+            }
+            while step < record.step && sim.advance()? {
+                step += 1;
+            }
+            let actual = (step == record.step).then(|| sim.state()).transpose()?;
+            if actual.as_ref() != Some(&record.state) {
+                return Ok(Some(Divergence {
+                    step: record.step,
+                    expected: record.state,
+                    actual,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The simulation for one part of `day` of `year`, set up from its input.
+pub fn simulation(year: u16, day: u8, part: Part, lines: &[String]) -> Result<Box<dyn Animation>> {
+    let solution = find_day(year, day)?.solution;
+    let input = solution.parse_input(lines)?;
+    solution
+        .simulate_input(&input, part)?
+        .with_context(|| format!("Day {day} of {year} has no simulation to trace"))
+}
+
+/// Run the simulation for one part of `day` of `year` from its input, recording a trace of it.
+/// Returns the number of steps run.
+pub fn record_day<W: Write>(
+    year: u16,
+    day: u8,
+    part: Part,
+    lines: &[String],
+    every: u64,
+    writer: W,
+) -> Result<u64> {
+    let mut sim = simulation(year, day, part, lines)?;
+    record(sim.as_mut(), year, day, part, every, writer)
+}
+
+/// Run the simulation a trace was recorded from, from the same input, and compare the two.
+pub fn replay_day<R: BufRead>(trace: Trace<R>, lines: &[String]) -> Result<Option<Divergence>> {
+    let mut sim = simulation(trace.year, trace.day, trace.part, lines)?;
+    trace.replay(sim.as_mut())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::day14::{parse_paths, Cave};
+    use crate::y2022::fixture;
+    #[test]
+    fn test_trace() -> Result<()> {
+        let lines = fixture(14, "example")?;
+        let mut cave = Cave::new(&parse_paths(&lines)?, false);
+        assert!(cave.run_until(|cave| cave.rested == 5)?);
+        let five = cave.snapshot();
+        cave.run()?;
+        assert_eq!(cave.rested, 24);
+        cave.restore(&five)?;
+        assert_eq!(cave.rested, 5);
+
+        let mut trace = vec![];
+        let steps = record_day(2022, 14, Part::One, &lines, 10, &mut trace)?;
+        let mut cave = Cave::new(&parse_paths(&lines)?, false);
+        let mut ran = 0;
+        while cave.step()? {
+            ran += 1;
+        }
+        assert_eq!(steps, ran);
+        let text = String::from_utf8(trace.clone())?;
+        assert_eq!(text.lines().count(), 2 + (steps as usize).div_ceil(10));
+        assert_eq!(replay_day(Trace::open(&trace[..])?, &lines)?, None);
+
+        let at_ten = Trace::open(&trace[..])?.state_at::<Cave>(10)?;
+        assert!(at_ten.is_some());
+
+        // Change the number of grains at rest in one state, as if another solver disagreed.
+        let tampered = text.replacen("\"rested\":3,", "\"rested\":4,", 1);
+        let divergence = replay_day(Trace::open(tampered.as_bytes())?, &lines)?
+            .context("expected a divergence")?;
+        assert!(divergence.actual.is_some());
+        assert_ne!(Some(divergence.expected), divergence.actual);
+
+        assert!(record_day(2022, 1, Part::One, &lines, 1, vec![]).is_err());
+        Ok(())
+    }
+}
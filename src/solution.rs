@@ -0,0 +1,212 @@
+//! The interface shared by every day's solution.
+
+use std::any::Any;
+use std::fmt;
+
+use anyhow::{bail, Context, Error, Result};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::render::Picture;
+use crate::simulation::Animation;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl PartialEq for Answer {
+    /// Numbers compare by value, whether or not they were signed.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::UInt(a), Answer::UInt(b)) => a == b,
+            (Answer::Int(a), Answer::UInt(b)) | (Answer::UInt(b), Answer::Int(a)) => {
+                u64::try_from(*a) == Ok(*b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Numbers are written as JSON numbers and text as strings.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => serializer.serialize_i64(*n),
+            Answer::UInt(n) => serializer.serialize_u64(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as _)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Int, i32, i64);
+answer_from!(UInt, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => bail!("There is no part {value}, only parts 1 and 2"),
+        }
+    }
+}
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed form.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    /// Parse the lines of the puzzle input.
+    fn parse(&self, lines: &[String]) -> Result<Self::Input>;
+    /// Solve part 1 of the puzzle.
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+    /// Solve part 2 of the puzzle.
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+    /// Draw the puzzle's state, for the days that have something to show.
+    fn render(&self, _input: &Self::Input) -> Result<Option<Picture>> {
+        Ok(None)
+    }
+    /// The simulation behind one part, for the days that run one step by step.
+    fn simulation(&self, _input: &Self::Input, _part: Part) -> Result<Option<Box<dyn Animation>>> {
+        Ok(None)
+    }
+}
+
+/// A parsed input whose type has been erased so that every day can share a registry.
+pub type AnyInput = Box<dyn Any + Send + Sync>;
+
+/// The object-safe view of a [`Solution`] used by the registry.
+pub trait Runner: Sync {
+    /// Parse the lines of the puzzle input, as [`Solution::parse`].
+    fn parse_input(&self, lines: &[String]) -> Result<AnyInput>;
+    /// Solve one part of the puzzle from an input returned by [`Runner::parse_input`].
+    fn run_part(&self, input: &AnyInput, part: Part) -> Result<Answer>;
+    /// Draw the puzzle's state from an input returned by [`Runner::parse_input`], as
+    /// [`Solution::render`].
+    fn render_input(&self, input: &AnyInput) -> Result<Option<Picture>>;
+    /// The simulation behind one part, from an input returned by [`Runner::parse_input`], as
+    /// [`Solution::simulation`].
+    fn simulate_input(&self, input: &AnyInput, part: Part) -> Result<Option<Box<dyn Animation>>>;
+}
+
+impl<S> Runner for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn parse_input(&self, lines: &[String]) -> Result<AnyInput> {
+        Ok(Box::new(self.parse(lines)?))
+    }
+
+    fn run_part(&self, input: &AnyInput, part: Part) -> Result<Answer> {
+        let input = downcast::<S>(input)?;
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn render_input(&self, input: &AnyInput) -> Result<Option<Picture>> {
+        self.render(downcast::<S>(input)?)
+    }
+
+    fn simulate_input(&self, input: &AnyInput, part: Part) -> Result<Option<Box<dyn Animation>>> {
+        self.simulation(downcast::<S>(input)?, part)
+    }
+}
+
+fn downcast<S>(input: &AnyInput) -> Result<&S::Input>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .context("Input was parsed by a different solution")
+}
+
+/// An entry in the registry of solved days.
+pub struct Day {
+    /// The day of December the puzzle was released.
+    pub number: u8,
+    pub solution: &'static dyn Runner,
+}
+
+/// A year of puzzles and the days solved in it.
+pub struct Year {
+    pub number: u16,
+    /// The solved days, in order.
+    pub days: &'static [Day],
+}
@@ -0,0 +1,242 @@
+//! A local store of puzzle inputs, with a manifest recording where each came from and its hash.
+//!
+//! Inputs are kept as `<year>/dayNN.txt` under the store directory, with line endings normalised
+//! to `\n`. The manifest is TOML with a table for each input, keyed by year and day number:
+//!
+//! ```toml
+//! [year.2022.day.1]
+//! sha256 = "5f2b…"
+//! imported_from = "input.txt"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The name of the store directory, kept inside the input directory.
+pub const STORE_DIR: &str = "inputs";
+
+/// The name of the manifest file at the top of the store.
+pub const MANIFEST_FILE: &str = "manifest.toml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    year: BTreeMap<String, YearEntries>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct YearEntries {
+    #[serde(default)]
+    day: BTreeMap<String, Entry>,
+}
+
+/// What the manifest records about one stored input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub sha256: String,
+    /// The name of the file it was imported from, without the directory, which would give away
+    /// where things are kept on the importer's machine.
+    pub imported_from: String,
+}
+
+/// How a stored input compares with what the manifest recorded for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// There is no stored input for the day.
+    Absent,
+    /// The input is stored but the manifest has no hash for it.
+    Unrecorded,
+    Unchanged,
+    /// The input has been changed since it was imported.
+    Changed {
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Absent => write!(f, "not stored"),
+            Status::Unrecorded => write!(f, "not in the manifest"),
+            Status::Unchanged => write!(f, "unchanged"),
+            Status::Changed { expected, actual } => {
+                write!(
+                    f,
+                    "changed since import: expected sha256 {expected}, found {actual}"
+                )
+            }
+        }
+    }
+}
+
+/// A directory of imported inputs and the manifest describing them.
+#[derive(Debug)]
+pub struct Store {
+    root: PathBuf,
+    manifest: Manifest,
+}
+
+impl Store {
+    /// Open the store at `root`, which need not exist yet.
+    pub fn open(root: &Path) -> Result<Self> {
+        let manifest_path = root.join(MANIFEST_FILE);
+        let manifest = if manifest_path.exists() {
+            let text = fs::read_to_string(&manifest_path)
+                .with_context(|| format!("Reading manifest {}", manifest_path.display()))?;
+            toml::from_str(&text)
+                .with_context(|| format!("Parsing manifest {}", manifest_path.display()))?
+        } else {
+            Manifest::default()
+        };
+        Ok(Store {
+            root: root.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// The store kept in `dir`, or in the working directory if not given.
+    pub fn in_dir(dir: Option<&Path>) -> Result<Self> {
+        Store::open(&dir.unwrap_or(Path::new(".")).join(STORE_DIR))
+    }
+
+    /// Where the input for `day` of `year` is kept.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// What the manifest records for `day` of `year`, if anything.
+    pub fn entry(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.manifest
+            .year
+            .get(&year.to_string())?
+            .day
+            .get(&day.to_string())
+    }
+
+    /// Copy `file` into the store as the input for `day` of `year`, replacing any input already
+    /// there, and record its hash in the manifest.
+    pub fn import(&mut self, year: u16, day: u8, file: &Path) -> Result<Entry> {
+        let text = fs::read_to_string(file)
+            .with_context(|| format!("Reading input file {}", file.display()))?;
+        let text = normalise(&text);
+        let path = self.path(year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Creating store directory {}", dir.display()))?;
+        }
+        fs::write(&path, &text).with_context(|| format!("Writing input {}", path.display()))?;
+
+        let entry = Entry {
+            sha256: sha256(text.as_bytes()),
+            imported_from: file
+                .file_name()
+                .unwrap_or(file.as_os_str())
+                .to_string_lossy()
+                .into_owned(),
+        };
+        let days = &mut self.manifest.year.entry(year.to_string()).or_default().day;
+        days.insert(day.to_string(), entry.clone());
+        self.save()?;
+        Ok(entry)
+    }
+
+    /// Compare the stored input for `day` of `year` with the hash recorded when it was imported.
+    pub fn status(&self, year: u16, day: u8) -> Result<Status> {
+        let path = self.path(year, day);
+        if !path.exists() {
+            return Ok(Status::Absent);
+        }
+        let Some(entry) = self.entry(year, day) else {
+            return Ok(Status::Unrecorded);
+        };
+        let bytes = fs::read(&path).with_context(|| format!("Reading input {}", path.display()))?;
+        let actual = sha256(&bytes);
+        Ok(if actual == entry.sha256 {
+            Status::Unchanged
+        } else {
+            Status::Changed {
+                expected: entry.sha256.clone(),
+                actual,
+            }
+        })
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = self.root.join(MANIFEST_FILE);
+        let text = toml::to_string(&self.manifest)?;
+        fs::write(&path, text).with_context(|| format!("Writing manifest {}", path.display()))
+    }
+}
+
+/// Turn `\r\n` and lone `\r` line endings into `\n`, and end the text with a newline.
+pub fn normalise(text: &str) -> String {
+    let mut text = text.replace("\r\n", "\n").replace('\r', "\n");
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+/// The SHA-256 of `bytes`, in lowercase hex.
+pub fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputSource;
+    #[test]
+    fn test_store() -> Result<()> {
+        assert_eq!(normalise("1\r\n2\r3"), "1\n2\n3\n");
+        assert_eq!(
+            sha256(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let dir = std::env::temp_dir().join(format!("aoc-store-{}", std::process::id()));
+        let original = dir.join("download.txt");
+        fs::create_dir_all(&dir)?;
+        fs::write(&original, "1000\r\n2000\r\n")?;
+
+        let mut store = Store::in_dir(Some(&dir))?;
+        assert_eq!(store.status(2022, 1)?, Status::Absent);
+        assert_eq!(
+            InputSource::for_day(2022, 1, Some(&dir))?,
+            InputSource::in_dir(2022, 1, Some(&dir))
+        );
+        assert_eq!(
+            store.import(2022, 1, &original)?.imported_from,
+            "download.txt"
+        );
+        assert_eq!(fs::read_to_string(store.path(2022, 1))?, "1000\n2000\n");
+
+        let store = Store::in_dir(Some(&dir))?;
+        assert_eq!(
+            store.entry(2022, 1).map(|e| e.sha256.clone()),
+            Some(sha256(b"1000\n2000\n"))
+        );
+        assert_eq!(store.status(2022, 1)?, Status::Unchanged);
+        assert_eq!(
+            InputSource::for_day(2022, 1, Some(&dir))?,
+            InputSource::File(store.path(2022, 1))
+        );
+        fs::write(store.path(2022, 1), "1000\n2001\n")?;
+        assert!(matches!(store.status(2022, 1)?, Status::Changed { .. }));
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Collecting how long each day took and summarising it.
+
+use std::io::Write;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::runner::DayResult;
+use crate::solution::Part;
+
+/// A timed step of running a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+/// How long each stage of each day took.
+#[derive(Debug, Default)]
+pub struct Timings {
+    entries: Vec<(u8, Stage, Duration)>,
+    /// How long the whole run took from start to finish, which is less than the total when
+    /// stages run in parallel.
+    wall: Option<Duration>,
+}
+
+impl Timings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, result: &DayResult) {
+        self.entries
+            .push((result.day, Stage::Parse, result.parse_elapsed));
+        for part in &result.parts {
+            self.entries
+                .push((part.day, Stage::Solve(part.part), part.elapsed));
+        }
+    }
+
+    pub fn set_wall(&mut self, elapsed: Duration) {
+        self.wall = Some(elapsed);
+    }
+
+    pub fn total(&self) -> Duration {
+        self.entries.iter().map(|(_, _, elapsed)| *elapsed).sum()
+    }
+
+    /// Write a table of every stage, slowest first, followed by the total and the wall time.
+    pub fn write_summary<W: Write>(&self, mut writer: W) -> Result<()> {
+        let mut entries = self.entries.clone();
+        entries.sort_by_key(|&(_, _, elapsed)| std::cmp::Reverse(elapsed));
+        writeln!(writer, "{:<16}{:>12}", "Task", "Time")?;
+        for (day, stage, elapsed) in entries {
+            let task = match stage {
+                Stage::Parse => format!("Day {day:>2} parse"),
+                Stage::Solve(part) => format!("Day {day:>2} part {part}"),
+            };
+            writeln!(writer, "{task:<16}{:>12}", format!("{elapsed:.2?}"))?;
+        }
+        writeln!(
+            writer,
+            "{:<16}{:>12}",
+            "Total",
+            format!("{:.2?}", self.total())
+        )?;
+        if let Some(wall) = self.wall {
+            writeln!(writer, "{:<16}{:>12}", "Wall", format!("{wall:.2?}"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartResult;
+
+    #[test]
+    fn test_summary() -> Result<()> {
+        let mut timings = Timings::new();
+        timings.record(&DayResult {
+            day: 11,
+            parse_elapsed: Duration::from_micros(40),
+            parts: vec![
+                PartResult {
+                    day: 11,
+                    part: Part::One,
+                    answer: 10605.into(),
+                    elapsed: Duration::from_micros(300),
+                },
+                PartResult {
+                    day: 11,
+                    part: Part::Two,
+                    answer: 2713310158u64.into(),
+                    elapsed: Duration::from_millis(25),
+                },
+            ],
+        });
+        assert_eq!(timings.total(), Duration::from_micros(25340));
+
+        let mut summary = vec![];
+        timings.write_summary(&mut summary)?;
+        assert_eq!(
+            String::from_utf8(summary)?,
+            "\
+Task                    Time
+Day 11 part 2        25.00ms
+Day 11 part 1       300.00µs
+Day 11 parse         40.00µs
+Total                25.34ms
+"
+        );
+        Ok(())
+    }
+}
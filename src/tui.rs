@@ -0,0 +1,453 @@
+//! An interactive dashboard for running the days and watching their simulations play out.
+
+use std::collections::BTreeMap;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::Duration;
+
+use anyhow::Result;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::error::report;
+use crate::input::InputSource;
+use crate::point::Point;
+use crate::render::Ink;
+use crate::runner::{run_day, DayResult};
+use crate::simulation::Animation;
+use crate::solution::{Day, Part};
+
+/// How long to wait for a key before drawing the next frame.
+const FRAME: Duration = Duration::from_millis(30);
+
+/// The simulation behind `part` of `day`, set up from its input, if it has one.
+fn animation(day: &Day, part: Part, lines: &[String]) -> Result<Option<Box<dyn Animation>>> {
+    let input = day.solution.parse_input(lines)?;
+    day.solution.simulate_input(&input, part)
+}
+
+/// Where a day has got to.
+enum Status {
+    Running,
+    /// The results, or the error report.
+    Done(Result<DayResult, String>),
+}
+
+/// A simulation being played, and how.
+struct Player {
+    animation: Box<dyn Animation>,
+    paused: bool,
+    /// The steps to take each frame.
+    speed: u32,
+    finished: bool,
+    error: Option<String>,
+    /// The top left cell in view, kept between frames so that the view only moves when the focus
+    /// gets near the edge.
+    view: Point<usize>,
+}
+
+impl Player {
+    fn new(animation: Box<dyn Animation>) -> Self {
+        Player {
+            animation,
+            paused: false,
+            speed: 1,
+            finished: false,
+            error: None,
+            view: Point::new(0, 0),
+        }
+    }
+
+    fn advance(&mut self, steps: u32) {
+        for _ in 0..steps {
+            if self.finished {
+                return;
+            }
+            match self.animation.advance() {
+                Ok(more) => self.finished = !more,
+                Err(e) => {
+                    self.finished = true;
+                    self.error = Some(format!("{e:#}"));
+                }
+            }
+        }
+    }
+}
+
+/// A day that has finished running in the background, with its results or the error report.
+type Finished = (u8, Result<DayResult, String>);
+
+/// The state of the dashboard.
+pub struct App {
+    year: u16,
+    days: Vec<(&'static Day, InputSource)>,
+    list: ListState,
+    status: BTreeMap<u8, Status>,
+    player: Option<Player>,
+    /// The part whose simulation to play.
+    part: Part,
+    message: Option<String>,
+    sender: Sender<Finished>,
+    receiver: Receiver<Finished>,
+    /// Where days run in the background, apart from the pool the dashboard itself runs in.
+    pool: ThreadPool,
+    quit: bool,
+}
+
+impl App {
+    /// A dashboard for `days` of `year`, reading each from the input given, and running them on
+    /// as many threads as the current pool has. Simulations play `part` until another is chosen.
+    pub fn new(year: u16, days: Vec<(&'static Day, InputSource)>, part: Part) -> Result<Self> {
+        let (sender, receiver) = channel();
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(rayon::current_num_threads())
+            .build()?;
+        Ok(App {
+            year,
+            days,
+            list: ListState::default().with_selected(Some(0)),
+            status: BTreeMap::new(),
+            player: None,
+            part,
+            message: None,
+            sender,
+            receiver,
+            pool,
+            quit: false,
+        })
+    }
+
+    /// Run the dashboard until it is quit.
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.quit {
+            while let Ok((day, result)) = self.receiver.try_recv() {
+                self.status.insert(day, Status::Done(result));
+            }
+            if let Some(player) = self.player.as_mut().filter(|p| !p.paused) {
+                player.advance(player.speed);
+            }
+            terminal.draw(|frame| self.draw(frame))?;
+            if event::poll(FRAME)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        self.handle_key(key.code);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn selected(&self) -> Option<&(&'static Day, InputSource)> {
+        self.days.get(self.list.selected()?)
+    }
+
+    /// Start running the day at `index` in the background.
+    fn start(&mut self, index: usize) {
+        let Some((day, source)) = self.days.get(index).cloned() else {
+            return;
+        };
+        if matches!(self.status.get(&day.number), Some(Status::Running)) {
+            return;
+        }
+        self.status.insert(day.number, Status::Running);
+        let sender = self.sender.clone();
+        self.pool.spawn(move || {
+            let result = run_day(day, &Part::ALL, &source).map_err(|e| report(&e));
+            // The dashboard may have been closed already, in which case nobody wants the result.
+            let _ = sender.send((day.number, result));
+        });
+    }
+
+    /// Start the selected day's simulation.
+    fn animate(&mut self) {
+        let Some((day, source)) = self.selected() else {
+            return;
+        };
+        let part = self.part;
+        let animation = source
+            .read_lines()
+            .and_then(|lines| animation(day, part, &lines));
+        match animation {
+            Ok(Some(animation)) => self.player = Some(Player::new(animation)),
+            Ok(None) => {
+                self.message = Some(format!("Day {} part {part} has no simulation", day.number))
+            }
+            Err(e) => self.message = Some(report(&e)),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        self.message = None;
+        if let Some(player) = &mut self.player {
+            match key {
+                KeyCode::Char(' ') => player.paused = !player.paused,
+                KeyCode::Char('.') | KeyCode::Right => {
+                    player.paused = true;
+                    player.advance(1);
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => {
+                    player.speed = (player.speed * 2).min(1 << 20)
+                }
+                KeyCode::Char('-') | KeyCode::Down => player.speed = (player.speed / 2).max(1),
+                KeyCode::Esc | KeyCode::Char('q') => self.player = None,
+                _ => {}
+            }
+            return;
+        }
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+            KeyCode::Enter | KeyCode::Char('r') => {
+                if let Some(index) = self.list.selected() {
+                    self.start(index);
+                }
+            }
+            KeyCode::Char('a') => (0..self.days.len()).for_each(|i| self.start(i)),
+            KeyCode::Char('v') => self.animate(),
+            KeyCode::Char('p') => {
+                self.part = match self.part {
+                    Part::One => Part::Two,
+                    Part::Two => Part::One,
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.quit = true,
+            _ => {}
+        }
+    }
+
+    /// Draw the list of days on the left, and the selected day or the simulation on the right.
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+        let [list, detail] =
+            Layout::horizontal([Constraint::Length(26), Constraint::Min(20)]).areas(main);
+
+        let items: Vec<_> = self
+            .days
+            .iter()
+            .map(|(day, _)| {
+                let state = match self.status.get(&day.number) {
+                    None => Span::raw(""),
+                    Some(Status::Running) => Span::styled("running", Color::Yellow),
+                    Some(Status::Done(Ok(result))) => {
+                        Span::styled(format!("{:.2?}", total_time(result)), Color::Green)
+                    }
+                    Some(Status::Done(Err(_))) => Span::styled("failed", Color::Red),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("Day {:>2}  ", day.number)),
+                    state,
+                ]))
+            })
+            .collect();
+        let days = List::new(items)
+            .block(Block::bordered().title(format!(" {} ", self.year)))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(days, list, &mut self.list);
+
+        let help_text = if self.player.is_some() {
+            "space pause · →/. step · +/- speed · esc back"
+        } else {
+            "↑/↓ select · enter run · a run all · v simulate · p part · q quit"
+        };
+        frame.render_widget(Line::from(help_text).style(Color::DarkGray), help);
+
+        match &mut self.player {
+            Some(player) => draw_player(frame, detail, player),
+            None => {
+                let text = self.detail();
+                frame.render_widget(Paragraph::new(text).block(Block::bordered()), detail);
+            }
+        }
+    }
+
+    /// The answers and timings of the selected day.
+    fn detail(&self) -> Text<'static> {
+        let Some((day, source)) = self.selected() else {
+            return Text::default();
+        };
+        let mut lines = vec![
+            Line::styled(format!("Day {}", day.number), Modifier::BOLD),
+            Line::raw(format!("Input: {source}")),
+            Line::raw(format!("Simulate: part {}", self.part)),
+            Line::raw(""),
+        ];
+        match self.status.get(&day.number) {
+            None => lines.push(Line::raw("Press enter to run")),
+            Some(Status::Running) => lines.push(Line::raw("Running…")),
+            Some(Status::Done(Ok(result))) => {
+                lines.push(Line::raw(format!("Parse    {:.2?}", result.parse_elapsed)));
+                for part in &result.parts {
+                    let answer = part.answer.to_string();
+                    let mut answer = answer.lines().map(str::to_string);
+                    lines.push(Line::raw(format!(
+                        "Part {}   {:.2?}   {}",
+                        part.part,
+                        part.elapsed,
+                        answer.next().unwrap_or_default()
+                    )));
+                    lines.extend(answer.map(Line::raw));
+                }
+            }
+            Some(Status::Done(Err(report))) => lines.extend(
+                report
+                    .lines()
+                    .map(|l| Line::styled(l.to_string(), Color::Red)),
+            ),
+        }
+        if let Some(message) = &self.message {
+            lines.push(Line::raw(""));
+            lines.extend(
+                message
+                    .lines()
+                    .map(|l| Line::styled(l.to_string(), Color::Yellow)),
+            );
+        }
+        Text::from(lines)
+    }
+}
+
+/// The total time spent parsing and solving a day.
+fn total_time(result: &DayResult) -> Duration {
+    result.parse_elapsed + result.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+}
+
+/// Draw as much of the simulation's picture as fits, keeping its focus in view, with its status
+/// underneath.
+fn draw_player(frame: &mut Frame, area: Rect, player: &mut Player) {
+    let mut title = player.animation.title();
+    if player.finished {
+        title += " (finished)";
+    } else if player.paused {
+        title += " (paused)";
+    }
+    let block = Block::bordered().title(format!(" {title} · {} steps/frame ", player.speed));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [canvas, status] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+    let picture = player.animation.picture();
+    let (width, height) = (canvas.width as usize, canvas.height as usize);
+    let focus = player.animation.focus().and_then(|p| picture.cell_at(p));
+    if let Some(focus) = focus {
+        player.view = Point::new(
+            follow(player.view.x, focus.x, width),
+            follow(player.view.y, focus.y, height),
+        );
+    }
+    player.view = Point::new(
+        player.view.x.min(picture.width().saturating_sub(width)),
+        player.view.y.min(picture.height().saturating_sub(height)),
+    );
+    let lines: Vec<_> = (player.view.y..picture.height().min(player.view.y + height))
+        .map(|y| {
+            let inks = (player.view.x..picture.width().min(player.view.x + width))
+                .map(|x| picture.ink_at(Point::new(x, y)));
+            Line::from(spans(inks))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), canvas);
+
+    let status_line = match &player.error {
+        Some(error) => Line::styled(error.clone(), Color::Red),
+        None => Line::raw(player.animation.status()),
+    };
+    frame.render_widget(status_line, status);
+}
+
+/// The first cell to show of `size` cells so that `focus` stays at least a quarter of the way in
+/// from either edge, moving on from `start` only if it has to.
+fn follow(start: usize, focus: usize, size: usize) -> usize {
+    let margin = size / 4;
+    if focus < start + margin {
+        focus.saturating_sub(margin)
+    } else if focus + margin >= start + size {
+        (focus + margin + 1).saturating_sub(size)
+    } else {
+        start
+    }
+}
+
+/// Each run of cells in the same ink as one coloured span.
+fn spans(inks: impl Iterator<Item = Ink>) -> Vec<Span<'static>> {
+    let mut spans = vec![];
+    let mut run = String::new();
+    let mut current: Option<Ink> = None;
+    for ink in inks {
+        if current.is_some_and(|c| c != ink) {
+            spans.push(span(&run, current));
+            run.clear();
+        }
+        run.push(ink.glyph);
+        current = Some(ink);
+    }
+    if !run.is_empty() {
+        spans.push(span(&run, current));
+    }
+    spans
+}
+
+fn span(text: &str, ink: Option<Ink>) -> Span<'static> {
+    let [r, g, b] = ink.map_or([255; 3], |ink| ink.rgb);
+    Span::styled(text.to_string(), Color::Rgb(r, g, b))
+}
+
+/// Show the dashboard for `days` of `year`, simulating `part` to begin with, until it is quit,
+/// restoring the terminal afterwards.
+pub fn run(year: u16, days: Vec<(&'static Day, InputSource)>, part: Part) -> Result<()> {
+    ratatui::run(|terminal| App::new(year, days, part)?.run(terminal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::fixture;
+    use crate::y2022::{DAYS, YEAR};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_dashboard() -> Result<()> {
+        let days = DAYS
+            .iter()
+            .map(|day| Ok((day, InputSource::for_day(YEAR, day.number, None)?)))
+            .collect::<Result<_>>()?;
+        let mut app = App::new(YEAR, days, Part::Two)?;
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Char('p'));
+        assert_eq!(app.part, Part::One);
+        let mut terminal = Terminal::new(TestBackend::new(60, 20))?;
+        terminal.draw(|frame| app.draw(frame))?;
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(screen.contains("Day  1"));
+        assert!(screen.contains("Press enter to run"));
+        assert!(screen.contains("Simulate: part 1"));
+
+        let mut player = Player::new(
+            animation(&DAYS[13], Part::Two, &fixture(YEAR, 14, "example")?)?
+                .expect("day 14 has a simulation"),
+        );
+        player.advance(10_000);
+        assert!(player.finished);
+        assert_eq!(player.animation.status(), "Grains at rest: 93");
+        terminal.draw(|frame| draw_player(frame, frame.area(), &mut player))?;
+
+        assert!(animation(&DAYS[0], Part::Two, &["1".to_string()])?.is_none());
+        assert_eq!(follow(0, 50, 40), 21);
+        assert_eq!(follow(21, 40, 40), 21);
+        assert_eq!(follow(21, 30, 40), 20);
+        Ok(())
+    }
+}
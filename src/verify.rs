@@ -0,0 +1,200 @@
+//! Checking answers against a file of known-good ones.
+//!
+//! An answers file is TOML with a table for each day, keyed by year and day number:
+//!
+//! ```toml
+//! [year.2022.day.1]
+//! part1 = 24000
+//! part2 = 45000
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::input::InputSource;
+use crate::runner::run_day;
+use crate::solution::{Answer, Day, Part};
+
+/// The name of the answers file kept alongside a set of inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnswersFile {
+    #[serde(default)]
+    year: BTreeMap<String, YearAnswers>,
+}
+
+#[derive(Deserialize)]
+struct YearAnswers {
+    #[serde(default)]
+    day: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Deserialize)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// The expected answer to each part, for one person's inputs.
+#[derive(Debug, Default)]
+pub struct AnswerSet {
+    answers: BTreeMap<(u16, u8, Part), Answer>,
+}
+
+impl AnswerSet {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Reading answers file {}", path.display()))?;
+        text.parse()
+            .with_context(|| format!("Parsing answers file {}", path.display()))
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(year, day, part))
+    }
+}
+
+impl std::str::FromStr for AnswerSet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let file: AnswersFile = toml::from_str(s)?;
+        let mut answers = BTreeMap::new();
+        for (year, year_answers) in file.year {
+            let year: u16 = year
+                .parse()
+                .with_context(|| format!("'{year}' is not a year"))?;
+            for (day, day_answers) in year_answers.day {
+                let day: u8 = day
+                    .parse()
+                    .with_context(|| format!("'{day}' is not a day number"))?;
+                for (part, answer) in [
+                    (Part::One, day_answers.part1),
+                    (Part::Two, day_answers.part2),
+                ] {
+                    if let Some(answer) = answer {
+                        answers.insert((year, day, part), answer);
+                    }
+                }
+            }
+        }
+        Ok(AnswerSet { answers })
+    }
+}
+
+/// How one part's answer compared with the expected answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    /// The answer was wrong, or the solution failed; the reason is given.
+    Fail(String),
+    /// There is no expected answer to compare with.
+    Missing,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "PASS"),
+            Outcome::Fail(reason) => write!(f, "FAIL ({reason})"),
+            Outcome::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+/// The outcome of checking one part of one day.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+pub fn check_answer(expected: Option<&Answer>, actual: &Answer) -> Outcome {
+    match expected {
+        Some(expected) if expected == actual => Outcome::Pass,
+        Some(expected) => Outcome::Fail(format!("expected {expected}, got {actual}")),
+        None => Outcome::Missing,
+    }
+}
+
+/// Run both parts of `day` of `year` and compare them with the expected answers.
+///
+/// A day with neither an input nor any expected answers is skipped, as not everyone has solved
+/// every day.
+pub fn verify_day(year: u16, day: &Day, source: &InputSource, answers: &AnswerSet) -> Vec<Check> {
+    let has_answers = Part::ALL
+        .iter()
+        .any(|&part| answers.expected(year, day.number, part).is_some());
+    if !has_answers && !source.exists() {
+        return vec![];
+    }
+    match run_day(day, &Part::ALL, source) {
+        Ok(result) => result
+            .parts
+            .iter()
+            .map(|r| Check {
+                day: r.day,
+                part: r.part,
+                outcome: check_answer(answers.expected(year, r.day, r.part), &r.answer),
+            })
+            .collect(),
+        Err(e) => Part::ALL
+            .iter()
+            .map(|&part| Check {
+                day: day.number,
+                part,
+                outcome: Outcome::Fail(format!("{e:#}")),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_answer_set() -> Result<()> {
+        let answers: AnswerSet = r#"
+            [year.2022.day.1]
+            part1 = 24000
+            part2 = 45000
+
+            [year.2022.day.5]
+            part1 = "CMZ"
+
+            [year.2023.day.1]
+            part1 = 142
+        "#
+        .parse()?;
+        assert_eq!(answers.expected(2023, 1, Part::One), Some(&142.into()));
+        assert_eq!(answers.expected(2022, 1, Part::One), Some(&24000.into()));
+        assert_eq!(answers.expected(2022, 5, Part::One), Some(&"CMZ".into()));
+        assert_eq!(answers.expected(2022, 5, Part::Two), None);
+
+        assert_eq!(
+            check_answer(answers.expected(2022, 1, Part::Two), &45000u32.into()),
+            Outcome::Pass
+        );
+        assert_eq!(
+            check_answer(answers.expected(2022, 5, Part::One), &"MCD".into()),
+            Outcome::Fail("expected CMZ, got MCD".to_string())
+        );
+        assert_eq!(
+            check_answer(answers.expected(2022, 5, Part::Two), &"MCD".into()),
+            Outcome::Missing
+        );
+
+        assert!("[year.2022.day.first]\npart1 = 1"
+            .parse::<AnswerSet>()
+            .is_err());
+        assert!("[day.1]\npart1 = 1".parse::<AnswerSet>().is_err());
+        Ok(())
+    }
+}
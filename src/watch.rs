@@ -0,0 +1,160 @@
+//! Re-running a day whenever its input file is saved, comparing each run with the one before.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+
+use crate::runner::DayResult;
+
+/// How often to look at the input file for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// When a file was last modified, and how long it was then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    modified: SystemTime,
+    len: u64,
+}
+
+impl Stamp {
+    /// The stamp of the file at `path`, or `None` if it is not there, as happens while some
+    /// editors save by replacing the file.
+    fn of(path: &Path) -> Result<Option<Self>> {
+        match fs::metadata(path) {
+            Ok(metadata) => Ok(Some(Stamp {
+                modified: metadata.modified()?,
+                len: metadata.len(),
+            })),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("Watching {}", path.display())),
+        }
+    }
+}
+
+/// Watches a file for changes by polling its modification time and length.
+#[derive(Debug)]
+pub struct InputWatcher {
+    path: PathBuf,
+    stamp: Option<Stamp>,
+}
+
+impl InputWatcher {
+    /// Watch `path` for changes from how it is now.
+    pub fn new(path: &Path) -> Result<Self> {
+        Ok(InputWatcher {
+            path: path.to_path_buf(),
+            stamp: Stamp::of(path)?,
+        })
+    }
+
+    /// Whether the file has changed since it was last looked at. A file that has gone missing has
+    /// not changed yet, as it is most likely partway through being saved.
+    pub fn changed(&mut self) -> Result<bool> {
+        let Some(stamp) = Stamp::of(&self.path)? else {
+            return Ok(false);
+        };
+        let changed = self.stamp != Some(stamp);
+        self.stamp = Some(stamp);
+        Ok(changed)
+    }
+
+    /// Block until the file changes, then until it has stopped changing for `poll`.
+    pub fn wait(&mut self, poll: Duration) -> Result<()> {
+        while !self.changed()? {
+            thread::sleep(poll);
+        }
+        loop {
+            thread::sleep(poll);
+            if !self.changed()? {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The answers and timings of `current`, each alongside the same part of `previous` if there was
+/// an earlier run.
+pub fn compare(previous: Option<&DayResult>, current: &DayResult) -> String {
+    let mut text = String::new();
+    let was = |elapsed: Option<Duration>| match elapsed {
+        Some(elapsed) => format!(", was {elapsed:.2?}"),
+        None => String::new(),
+    };
+    let _ = writeln!(
+        text,
+        "Day {:>2} parse: {:.2?}{}",
+        current.day,
+        current.parse_elapsed,
+        was(previous.map(|p| p.parse_elapsed))
+    );
+    for part in &current.parts {
+        let before = previous.and_then(|p| p.parts.iter().find(|b| b.part == part.part));
+        let answer = part.answer.to_string();
+        let change = match before {
+            None => String::new(),
+            Some(before) if before.answer == part.answer => " (unchanged)".to_string(),
+            Some(before) if answer.contains('\n') => format!(" (was:\n{})", before.answer),
+            Some(before) => format!(" (was {})", before.answer),
+        };
+        let timing = format!("{:.2?}{}", part.elapsed, was(before.map(|b| b.elapsed)));
+        let _ = if answer.contains('\n') {
+            writeln!(
+                text,
+                "Day {:>2} part {} in {timing}:\n{answer}{change}",
+                part.day, part.part
+            )
+        } else {
+            writeln!(
+                text,
+                "Day {:>2} part {} in {timing}: {answer}{change}",
+                part.day, part.part
+            )
+        };
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartResult;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_watch() -> Result<()> {
+        let result = |answer: u32, millis| DayResult {
+            day: 14,
+            parse_elapsed: Duration::from_micros(50),
+            parts: vec![PartResult {
+                day: 14,
+                part: Part::One,
+                answer: answer.into(),
+                elapsed: Duration::from_millis(millis),
+            }],
+        };
+        assert_eq!(
+            compare(None, &result(24, 1)),
+            "Day 14 parse: 50.00µs\nDay 14 part 1 in 1.00ms: 24\n"
+        );
+        assert_eq!(
+            compare(Some(&result(24, 1)), &result(25, 2)),
+            "Day 14 parse: 50.00µs, was 50.00µs\nDay 14 part 1 in 2.00ms, was 1.00ms: 25 (was 24)\n"
+        );
+        assert!(compare(Some(&result(24, 1)), &result(24, 1)).ends_with("24 (unchanged)\n"));
+
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        fs::write(&path, "498,4 -> 498,6\n")?;
+        let mut watcher = InputWatcher::new(&path)?;
+        assert!(!watcher.changed()?);
+        fs::write(&path, "498,4 -> 498,6 -> 496,6\n")?;
+        assert!(watcher.changed()?);
+        assert!(!watcher.changed()?);
+        fs::remove_file(&path)?;
+        assert!(!watcher.changed()?);
+        Ok(())
+    }
+}
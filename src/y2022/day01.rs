@@ -0,0 +1,64 @@
+use anyhow::{bail, Context, Result};
+
+use crate::error::ParseError;
+use crate::parse::{blocks, unsigned};
+use crate::solution::{Answer, Solution};
+
+/// Day 1: Calorie Counting.
+pub struct Day01;
+
+/// The total calories carried by each Elf, from lists separated by blank lines.
+pub fn parse_inventory<I>(lines: I) -> Result<Vec<u64>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    blocks(lines)
+        .into_iter()
+        .map(|block| {
+            let calories = block.parse_lines(unsigned::<u32>)?;
+            Ok(calories.into_iter().map(u64::from).sum())
+        })
+        .collect()
+}
+
+/// The total calories carried by the `number` Elves carrying the most.
+pub fn get_max(calories_per_elf: &[u64], number: usize) -> Result<u64> {
+    let mut calories_per_elf = calories_per_elf.to_vec();
+    calories_per_elf.sort();
+
+    if calories_per_elf.len() < number {
+        bail!("Not enough Elves");
+    }
+
+    Ok(calories_per_elf.iter().rev().take(number).sum())
+}
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_inventory(lines).context("Getting calorie inventory")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(get_max(input, 1)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(get_max(input, 3)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day1() -> Result<()> {
+        let inventory = parse_inventory(fixture(1, "example")?)?;
+        assert_eq!(get_max(&inventory, 1)?, 24000);
+        assert_eq!(get_max(&inventory, 3)?, 45000);
+        Ok(())
+    }
+}
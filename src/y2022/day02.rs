@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use nom::{branch::alt, character::complete::char, combinator::value, sequence::separated_pair};
+
+use crate::error::ParseError;
+use crate::parse::{parse_lines, PResult};
+use crate::solution::{Answer, Solution};
+
+/// Day 2: Rock Paper Scissors.
+pub struct Day02;
+
+/// A shape played in a round of Rock Paper Scissors.
+#[derive(Clone, Copy)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+enum GameResult {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// The second column of the strategy guide, whose meaning differs between the two parts.
+#[derive(Clone, Copy)]
+pub enum Code {
+    X,
+    Y,
+    Z,
+}
+
+fn round(s: &str) -> PResult<'_, (Rps, Code)> {
+    separated_pair(
+        alt((
+            value(Rps::Rock, char('A')),
+            value(Rps::Paper, char('B')),
+            value(Rps::Scissors, char('C')),
+        )),
+        char(' '),
+        alt((
+            value(Code::X, char('X')),
+            value(Code::Y, char('Y')),
+            value(Code::Z, char('Z')),
+        )),
+    )(s)
+}
+
+/// Read the strategy guide into the opponent's move and the code for each round.
+pub fn parse_strategy<I>(lines: I) -> Result<Vec<(Rps, Code)>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_lines(lines, round)
+}
+
+fn score(play: Rps, result: GameResult) -> u32 {
+    let shape_score = match play {
+        Rps::Rock => 1,
+        Rps::Paper => 2,
+        Rps::Scissors => 3,
+    };
+
+    let play_score = match result {
+        GameResult::Lose => 0,
+        GameResult::Draw => 3,
+        GameResult::Win => 6,
+    };
+
+    shape_score + play_score
+}
+
+/// The total score when the code says which shape to play.
+pub fn calculate_move_score(rounds: &[(Rps, Code)]) -> u32 {
+    rounds
+        .iter()
+        .map(|(other_move, code)| {
+            let play = match code {
+                Code::X => Rps::Rock,
+                Code::Y => Rps::Paper,
+                Code::Z => Rps::Scissors,
+            };
+
+            let result = match (other_move, &play) {
+                (Rps::Rock, Rps::Rock) => GameResult::Draw,
+                (Rps::Rock, Rps::Paper) => GameResult::Win,
+                (Rps::Rock, Rps::Scissors) => GameResult::Lose,
+                (Rps::Paper, Rps::Rock) => GameResult::Lose,
+                (Rps::Paper, Rps::Paper) => GameResult::Draw,
+                (Rps::Paper, Rps::Scissors) => GameResult::Win,
+                (Rps::Scissors, Rps::Rock) => GameResult::Win,
+                (Rps::Scissors, Rps::Paper) => GameResult::Lose,
+                (Rps::Scissors, Rps::Scissors) => GameResult::Draw,
+            };
+
+            score(play, result)
+        })
+        .sum()
+}
+
+/// The total score when the code says how the round should end.
+pub fn calculate_rps_score(rounds: &[(Rps, Code)]) -> u32 {
+    rounds
+        .iter()
+        .map(|(other_move, code)| {
+            let desired_result = match code {
+                Code::X => GameResult::Lose,
+                Code::Y => GameResult::Draw,
+                Code::Z => GameResult::Win,
+            };
+
+            let play = match (other_move, &desired_result) {
+                (Rps::Rock, GameResult::Lose) => Rps::Scissors,
+                (Rps::Rock, GameResult::Draw) => Rps::Rock,
+                (Rps::Rock, GameResult::Win) => Rps::Paper,
+                (Rps::Paper, GameResult::Lose) => Rps::Rock,
+                (Rps::Paper, GameResult::Draw) => Rps::Paper,
+                (Rps::Paper, GameResult::Win) => Rps::Scissors,
+                (Rps::Scissors, GameResult::Lose) => Rps::Paper,
+                (Rps::Scissors, GameResult::Draw) => Rps::Scissors,
+                (Rps::Scissors, GameResult::Win) => Rps::Rock,
+            };
+
+            score(play, desired_result)
+        })
+        .sum()
+}
+
+impl Solution for Day02 {
+    type Input = Vec<(Rps, Code)>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_strategy(lines).context("Reading strategy guide")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(calculate_move_score(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(calculate_rps_score(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day2() -> Result<()> {
+        let rounds = parse_strategy(fixture(2, "example")?)?;
+        assert_eq!(calculate_move_score(&rounds), 15);
+        assert_eq!(calculate_rps_score(&rounds), 12);
+        Ok(())
+    }
+}
@@ -1,12 +1,21 @@
 use std::collections::HashSet;
-use std::io::{BufRead, BufReader};
 
 use anyhow::{bail, Context, Result};
 use itertools::Itertools;
+use nom::{character::complete::alpha1, combinator::map};
 
-use crate::read_lines;
+use crate::parse::{parse_lines, PResult};
+use crate::solution::{Answer, Solution};
 
-fn calculate_backpack_score<I>(lines: I) -> Result<u32>
+/// Day 3: Rucksack Reorganization.
+pub struct Day03;
+
+fn rucksack(s: &str) -> PResult<'_, String> {
+    map(alpha1, str::to_string)(s)
+}
+
+/// The sum of the priorities of the item found in both compartments of each rucksack.
+pub fn calculate_backpack_score<I>(lines: I) -> Result<u32>
 where
     I: IntoIterator,
     I::Item: AsRef<str>,
@@ -14,6 +23,9 @@ where
     let mut item_priorities = vec![];
     for line in lines {
         let line = line.as_ref();
+        if !line.is_ascii() {
+            bail!("Rucksack {line:?} holds an item that is not a letter");
+        }
         let compartment_size = line.len() / 2;
         let left: HashSet<char> = HashSet::from_iter(line[..compartment_size].chars());
         let right: HashSet<char> = HashSet::from_iter(line[compartment_size..].chars());
@@ -32,7 +44,8 @@ where
     Ok(item_priorities.iter().sum())
 }
 
-fn identify_group_badge<I>(lines: I) -> Result<u32>
+/// The sum of the priorities of the badge shared by each group of three Elves.
+pub fn identify_group_badge<I>(lines: I) -> Result<u32>
 where
     I: IntoIterator,
     I::Item: AsRef<str>,
@@ -45,10 +58,12 @@ where
             let backpack: HashSet<char> = HashSet::from_iter(line.chars());
             group_backpacks.push(backpack);
         }
+        if group_backpacks.len() < 3 {
+            bail!("The last group has only {} Elves", group_backpacks.len());
+        }
         let first_intersection = HashSet::from_iter(
             group_backpacks[0]
                 .intersection(&group_backpacks[1])
-                .into_iter()
                 .copied(),
         );
         let total_intersection = first_intersection
@@ -66,41 +81,35 @@ where
     Ok(group_priorities.iter().sum())
 }
 
-pub fn day3() -> Result<(u32, u32)> {
-    let a =
-        calculate_backpack_score(read_lines!("day03.txt")).context("Calculating backpack score")?;
-    let b = identify_group_badge(read_lines!("day03.txt")).context("Calculating group badges")?;
-    Ok((a, b))
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_lines(lines, rucksack).context("Reading rucksacks")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(calculate_backpack_score(input)
+            .context("Calculating backpack score")?
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(identify_group_badge(input)
+            .context("Calculating group badges")?
+            .into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::y2022::fixture;
     #[test]
     fn test_day3() -> Result<()> {
-        assert_eq!(
-            calculate_backpack_score(vec![
-                "vJrwpWtwJgWrhcsFMMfFFhFp",
-                "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
-                "PmmdzqPrVvPwwTWBwg",
-                "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
-                "ttgJtRGJQctTZtZT",
-                "CrZsJsPPZsGzwwsLwLmpwMDw"
-            ])?,
-            157
-        );
-        assert_eq!(
-            identify_group_badge(vec![
-                "vJrwpWtwJgWrhcsFMMfFFhFp",
-                "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
-                "PmmdzqPrVvPwwTWBwg",
-                "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
-                "ttgJtRGJQctTZtZT",
-                "CrZsJsPPZsGzwwsLwLmpwMDw"
-            ])?,
-            70
-        );
-        assert_eq!(day3()?, (8240, 2587));
+        assert_eq!(calculate_backpack_score(fixture(3, "example")?)?, 157);
+        assert_eq!(identify_group_badge(fixture(3, "example")?)?, 70);
+        assert!(calculate_backpack_score(fixture(3, "non-ascii")?).is_err());
         Ok(())
     }
 }
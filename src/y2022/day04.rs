@@ -0,0 +1,100 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{Context, Result};
+use nom::{character::complete::char, sequence::separated_pair};
+
+use crate::error::ParseError;
+use crate::parse::{hint, parse_lines, range, PResult};
+use crate::solution::{Answer, Solution};
+
+/// Day 4: Camp Cleanup.
+pub struct Day04;
+
+/// The sections assigned to each of a pair of Elves.
+pub type AssignmentPair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+fn assignment_pair(s: &str) -> PResult<'_, AssignmentPair> {
+    separated_pair(
+        hint("expected the first Elf's sections, like `2-4`", range),
+        hint(
+            "expected a comma between the two Elves' sections",
+            char(','),
+        ),
+        hint("expected the second Elf's sections, like `6-8`", range),
+    )(s)
+}
+
+/// Read the section assignments for each pair of Elves.
+pub fn parse_assignments<I>(lines: I) -> Result<Vec<AssignmentPair>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_lines(lines, assignment_pair)
+}
+
+/// The number of pairs where one range contains the other, or where they overlap at all if
+/// `include_partial` is set.
+pub fn overlapping_assignments(pairs: &[AssignmentPair], include_partial: bool) -> u32 {
+    let mut num_overlap = 0;
+    for (a, b) in pairs {
+        if include_partial {
+            if a.start() <= b.end() && a.end() >= b.start() {
+                num_overlap += 1;
+            }
+        } else if (a.contains(b.start()) && a.contains(b.end()))
+            || (b.contains(a.start()) && b.contains(a.end()))
+        {
+            num_overlap += 1;
+        }
+    }
+
+    num_overlap
+}
+
+impl Solution for Day04 {
+    type Input = Vec<AssignmentPair>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_assignments(lines).context("Reading section assignments")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(overlapping_assignments(input, false).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(overlapping_assignments(input, true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    use proptest::prelude::*;
+    #[test]
+    fn test_day4() -> Result<()> {
+        let pairs = parse_assignments(fixture(4, "example")?)?;
+        assert_eq!(overlapping_assignments(&pairs, false), 2);
+        assert_eq!(overlapping_assignments(&pairs, true), 4);
+        Ok(())
+    }
+
+    fn sections() -> impl Strategy<Value = RangeInclusive<u32>> {
+        (0u32..100, 0u32..20).prop_map(|(start, len)| start..=start + len)
+    }
+
+    proptest! {
+        #[test]
+        fn overlap_includes_containment(
+            pairs in prop::collection::vec((sections(), sections()), 0..20),
+        ) {
+            for pair in &pairs {
+                let pair = std::slice::from_ref(pair);
+                let partial = overlapping_assignments(pair, true);
+                prop_assert!(partial >= overlapping_assignments(pair, false));
+            }
+        }
+    }
+}
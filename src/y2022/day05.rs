@@ -0,0 +1,169 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Context, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::parse::{blocks, hint, unsigned, PResult};
+use crate::solution::{Answer, Solution};
+
+/// Day 5: Supply Stacks.
+pub struct Day05;
+
+/// Move `number` crates from stack `from` to stack `to`, counting stacks from 1.
+pub struct Move {
+    pub number: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// The starting stacks of crates, bottom first, and the moves to make.
+pub struct Procedure {
+    pub stacks: Vec<VecDeque<char>>,
+    pub moves: Vec<Move>,
+}
+
+/// The model of crane doing the rearranging.
+pub enum Crane {
+    /// Moves crates one at a time.
+    CrateMover9000,
+    /// Moves several crates at once, keeping their order.
+    CrateMover9001,
+}
+
+fn crane_move(s: &str) -> PResult<'_, Move> {
+    map(
+        tuple((
+            preceded(
+                hint("expected a move like `move 1 from 2 to 3`", tag("move ")),
+                hint("expected the number of crates to move", unsigned),
+            ),
+            preceded(
+                hint(
+                    "expected ` from ` and the stack to move from",
+                    tag(" from "),
+                ),
+                hint("expected the stack to move from", unsigned),
+            ),
+            preceded(
+                hint("expected ` to ` and the stack to move to", tag(" to ")),
+                hint("expected the stack to move to", unsigned),
+            ),
+        )),
+        |(number, from, to)| Move { number, from, to },
+    )(s)
+}
+
+fn stack_numbers(s: &str) -> PResult<'_, Vec<usize>> {
+    hint(
+        "expected the stack numbers under the drawing, like ` 1   2   3 `",
+        delimited(space0, separated_list1(space1, unsigned), space0),
+    )(s)
+}
+
+/// Read the drawing of the starting stacks followed by the list of moves.
+pub fn parse_procedure<I>(lines: I) -> Result<Procedure, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut blocks = blocks(lines).into_iter();
+    let drawing = blocks
+        .next()
+        .ok_or(ParseError::missing("drawing of the stacks"))?;
+    let moves = blocks.next().ok_or(
+        ParseError::missing("list of moves")
+            .with_hint("leave a blank line between the drawing and the moves"),
+    )?;
+    if let Some(extra) = blocks.next() {
+        return Err(ParseError::new(ErrorKind::Unexpected, &extra.lines[0])
+            .at_line(extra.first_line)
+            .at_column(1)
+            .with_source(&extra.lines[0])
+            .with_hint("expected the moves to end here"));
+    }
+    let numbers = drawing.lines.len() - 1;
+    let num_stacks = drawing.parse_line(numbers, stack_numbers)?.len();
+
+    let mut stacks = vec![VecDeque::new(); num_stacks];
+    for line in drawing.lines[..numbers].iter().rev() {
+        for (stack, crate_code) in stacks.iter_mut().zip(line.chars().skip(1).step_by(4)) {
+            if crate_code != ' ' {
+                stack.push_back(crate_code);
+            }
+        }
+    }
+    let moves = moves.parse_lines(crane_move)?;
+    Ok(Procedure { stacks, moves })
+}
+
+/// Carry out the procedure with `crane`, returning the crate on top of each stack.
+pub fn reorder_stacks(procedure: &Procedure, crane: Crane) -> Result<String> {
+    let mut stacks = procedure.stacks.clone();
+    let count = stacks.len();
+    for m in &procedure.moves {
+        for stack in [m.from, m.to] {
+            if !(1..=count).contains(&stack) {
+                bail!("There is no stack {stack}, only stacks 1 to {count}");
+            }
+        }
+        let mut staging: VecDeque<char> = VecDeque::new();
+        for _ in 0..m.number {
+            let moving_crate = stacks[m.from - 1]
+                .pop_back()
+                .context("Getting crate from stack")?;
+            staging.push_back(moving_crate);
+        }
+        for _ in 0..m.number {
+            let moving_crate = match crane {
+                Crane::CrateMover9000 => staging.pop_front(),
+                Crane::CrateMover9001 => staging.pop_back(),
+            }
+            .context("Getting crate from stack")?;
+            stacks[m.to - 1].push_back(moving_crate);
+        }
+    }
+    stacks
+        .iter()
+        .map(|s| s.back().context("Getting top crate"))
+        .collect()
+}
+
+impl Solution for Day05 {
+    type Input = Procedure;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_procedure(lines).context("Reading rearrangement procedure")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(reorder_stacks(input, Crane::CrateMover9000)
+            .context("Reordering crate stacks")?
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(reorder_stacks(input, Crane::CrateMover9001)
+            .context("Reordering crate stacks")?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day5() -> Result<()> {
+        let procedure = parse_procedure(fixture(5, "example")?)?;
+        assert_eq!(reorder_stacks(&procedure, Crane::CrateMover9000)?, "CMZ");
+        assert_eq!(reorder_stacks(&procedure, Crane::CrateMover9001)?, "MCD");
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use nom::character::complete::alpha1;
+
+use crate::error::ParseError;
+use crate::parse::{parse_line, Failure};
+use crate::solution::{Answer, Solution};
+
+/// Day 6: Tuning Trouble.
+pub struct Day06;
+
+/// The kinds of marker in the datastream.
+pub enum MessageMarker {
+    StartOfPacket,
+    StartOfMessage,
+}
+
+/// The number of characters read before the end of the first marker of `marker_type`.
+pub fn find_start_marker<S: ToString>(message: S, marker_type: MessageMarker) -> Result<usize> {
+    let mut answer = None;
+    let window_size = match marker_type {
+        MessageMarker::StartOfPacket => 4,
+        MessageMarker::StartOfMessage => 14,
+    };
+    for (i, window) in message
+        .to_string()
+        .as_bytes()
+        .windows(window_size)
+        .enumerate()
+    {
+        let unique = &window.iter().unique().count();
+        if unique == &window.len() {
+            answer = Some(i + window_size);
+            break;
+        }
+    }
+    answer.context("getting marker")
+}
+
+impl Solution for Day06 {
+    type Input = String;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        let line = lines.first().ok_or(ParseError::missing("datastream"))?;
+        Ok(parse_line(alpha1::<_, Failure>, line)
+            .map_err(|e| e.at_line(1))?
+            .to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(find_start_marker(input, MessageMarker::StartOfPacket)
+            .context("finding start-of-packet marker")?
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(find_start_marker(input, MessageMarker::StartOfMessage)
+            .context("finding start-of-message marker")?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day6() -> Result<()> {
+        let examples = fixture(6, "examples")?;
+        let start_of_packet = [7, 5, 6, 10, 11];
+        let start_of_message = [19, 23, 23, 29, 26];
+        for (i, example) in examples.iter().enumerate() {
+            assert_eq!(
+                find_start_marker(example, MessageMarker::StartOfPacket)?,
+                start_of_packet[i]
+            );
+            assert_eq!(
+                find_start_marker(example, MessageMarker::StartOfMessage)?,
+                start_of_message[i]
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, not_line_ending},
+    combinator::{map, value},
+    sequence::{pair, preceded, separated_pair},
+};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::parse::{expect, parse_line, unsigned, PResult};
+use crate::solution::{Answer, Solution};
+
+/// Day 7: No Space Left On Device.
+pub struct Day07;
+
+/// A line of terminal output: a command, or an entry listed by `ls`.
+#[derive(Clone)]
+enum Output<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir,
+    File(u32, &'a str),
+}
+
+fn terminal_line(s: &str) -> PResult<'_, Output<'_>> {
+    alt((
+        preceded(
+            tag("$ "),
+            expect(
+                ErrorKind::UnknownInstruction,
+                alt((
+                    map(preceded(tag("cd "), not_line_ending), Output::Cd),
+                    value(Output::Ls, tag("ls")),
+                )),
+            ),
+        ),
+        value(Output::Dir, pair(tag("dir "), not_line_ending)),
+        map(
+            separated_pair(unsigned, char(' '), not_line_ending),
+            |(size, name)| Output::File(size, name),
+        ),
+    ))(s)
+}
+
+/// Replay the terminal output, returning every directory visited and the size of every file.
+pub fn construct_fs<I>(lines: I) -> Result<(HashSet<PathBuf>, HashMap<PathBuf, u32>), ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut fs: HashMap<_, u32> = HashMap::new();
+    let mut all_dirs = HashSet::new();
+    let mut current_path = PathBuf::new();
+    for (i, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref();
+        let output = parse_line(terminal_line, line).map_err(|e| e.at_line(i + 1))?;
+        match output {
+            Output::Cd("/") => current_path = PathBuf::from("/"),
+            Output::Cd("..") => {
+                current_path.pop();
+            }
+            Output::Cd(subdir) => current_path.push(subdir),
+            Output::Ls | Output::Dir => continue,
+            Output::File(size, name) => {
+                fs.insert(current_path.join(name), size);
+                continue;
+            }
+        }
+        all_dirs.insert(current_path.clone());
+    }
+    Ok((all_dirs, fs))
+}
+
+/// The total size of every directory, including the contents of its subdirectories.
+pub fn dir_sizes<I>(lines: I) -> Result<HashMap<PathBuf, u64>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let (all_dirs, fs) = construct_fs(lines)?;
+    let dir_sizes = all_dirs.into_iter().map(|d| {
+        let size = fs
+            .iter()
+            .filter(|(p, _)| p.starts_with(&d))
+            .map(|(_, &s)| u64::from(s))
+            .sum::<u64>();
+        (d, size)
+    });
+    Ok(dir_sizes.collect())
+}
+
+/// The sum of the sizes of the directories of at most 100000.
+pub fn sum_small_dirs(dir_sizes: &HashMap<PathBuf, u64>) -> u64 {
+    dir_sizes.values().filter(|s| s <= &&100000).sum()
+}
+
+/// The size of the smallest directory whose deletion frees enough space for the update.
+pub fn find_freeing_dir(dir_sizes: &HashMap<PathBuf, u64>) -> Result<u64> {
+    let total_space: u64 = 70000000;
+    let required: u64 = 30000000;
+    let currently_used: u64 = *dir_sizes
+        .get(&PathBuf::from("/"))
+        .context("finding root dir")?;
+    let currently_free = total_space
+        .checked_sub(currently_used)
+        .context("The files take up more space than the disk has")?;
+    let deficit = required.saturating_sub(currently_free);
+    let freeing_dir = dir_sizes
+        .iter()
+        .filter(|(_, s)| s >= &&deficit)
+        .min_by(|(_, s), (_, s_other)| s.cmp(s_other))
+        .context("finding min dir to free space")?;
+    Ok(*freeing_dir.1)
+}
+
+impl Solution for Day07 {
+    type Input = HashMap<PathBuf, u64>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        dir_sizes(lines).context("Reconstructing filesystem")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sum_small_dirs(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(find_freeing_dir(input)
+            .context("Finding dir to free space")?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    use proptest::prelude::*;
+    #[test]
+    fn test_day7() -> Result<()> {
+        let dir_sizes = dir_sizes(fixture(7, "example")?)?;
+        assert_eq!(sum_small_dirs(&dir_sizes), 95437);
+        assert_eq!(find_freeing_dir(&dir_sizes)?, 24933642);
+        Ok(())
+    }
+
+    /// A terminal session listing each file from the root, given as its directories, its name
+    /// and its size.
+    fn session() -> impl Strategy<Value = Vec<String>> {
+        let file = (prop::collection::vec("[a-c]", 0..4), "[x-z]", 1u32..1000);
+        prop::collection::vec(file, 0..20).prop_map(|files| {
+            let mut lines = vec![];
+            for (dirs, name, size) in files {
+                lines.push("$ cd /".to_string());
+                for dir in dirs {
+                    lines.push(format!("$ cd {dir}"));
+                }
+                lines.push("$ ls".to_string());
+                lines.push(format!("{size} {name}.txt"));
+            }
+            lines
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn sizes_grow_up_the_tree(lines in session()) {
+            let sizes = dir_sizes(&lines)?;
+            for (dir, size) in &sizes {
+                if let Some(parent) = dir.parent().and_then(|parent| sizes.get(parent)) {
+                    prop_assert!(parent >= size, "{} is larger than its parent", dir.display());
+                }
+            }
+        }
+    }
+}
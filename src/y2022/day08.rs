@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::point::Direction;
+use crate::render::{Palette, Picture};
+use crate::solution::{Answer, Solution};
+
+/// Day 8: Treetop Tree House.
+pub struct Day08;
+
+/// Read the map of tree heights.
+pub fn lines_to_grid<I>(lines: I) -> Result<Grid<u32>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Grid::parse(lines, |_, d| d.to_digit(10))
+}
+
+/// The number of trees visible from outside the grid.
+pub fn count_visible_trees(grid: &Grid<u32>) -> u32 {
+    let num_visible = grid
+        .indexed_iter()
+        .filter(|&(pos, h)| {
+            Direction::ALL
+                .iter()
+                .any(|d| grid.ray(pos, d.step()).all(|t| grid[t] < *h))
+        })
+        .count();
+    num_visible as u32
+}
+
+/// The highest scenic score of any tree.
+pub fn max_scenic_score(grid: &Grid<u32>) -> Result<u32> {
+    let num_visible: usize = grid
+        .indexed_iter()
+        .map(|(pos, h)| {
+            Direction::ALL
+                .iter()
+                .map(|d| {
+                    let mut trees = 0;
+                    for t in grid.ray(pos, d.step()) {
+                        trees += 1;
+                        if grid[t] >= *h {
+                            break;
+                        }
+                    }
+                    trees
+                })
+                .product()
+        })
+        .max()
+        .context("Finding max scenic score")?;
+    Ok(num_visible as u32)
+}
+
+/// The forest, shaded from the shortest trees to the tallest.
+pub fn draw_forest(grid: &Grid<u32>) -> Picture {
+    let palette = Palette::gradient([20, 40, 10], [120, 230, 60], "0123456789");
+    Picture::from_grid(grid, palette, |&h| h as u8)
+}
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        lines_to_grid(lines).context("Reading tree heights")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(count_visible_trees(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(max_scenic_score(input)?.into())
+    }
+
+    fn render(&self, input: &Self::Input) -> Result<Option<Picture>> {
+        Ok(Some(draw_forest(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day8() -> Result<()> {
+        let grid = lines_to_grid(fixture(8, "example")?)?;
+        assert_eq!(count_visible_trees(&grid), 21);
+        assert_eq!(max_scenic_score(&grid)?, 8);
+        assert_eq!(
+            draw_forest(&grid).to_ascii(),
+            fixture(8, "example")?.join("\n")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nom::{character::complete::char, sequence::separated_pair};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ParseError;
+use crate::parse::{direction, hint, parse_line, parse_lines, unsigned, PResult};
+use crate::point::{Direction, Point};
+use crate::render::{Ink, Palette, Picture};
+use crate::simulation::{Animation, Simulation};
+use crate::solution::{Answer, Part, Solution};
+
+/// Day 9: Rope Bridge.
+pub struct Day09;
+
+fn head_move(s: &str) -> PResult<'_, (Direction, u32)> {
+    separated_pair(
+        hint("expected one of `U`, `D`, `L` or `R`", direction),
+        hint("expected a space after the direction", char(' ')),
+        hint("expected the number of steps", unsigned),
+    )(s)
+}
+
+/// Read a move such as `R 4`.
+pub fn parse_move(text: &str) -> Result<(Direction, u32), ParseError> {
+    parse_line(head_move, text)
+}
+
+/// Read a move from each line.
+pub fn parse_moves<I>(lines: I) -> Result<Vec<(Direction, u32)>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_lines(lines, head_move)
+}
+
+/// A rope being pulled about by its head, one step at a time.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    moves: Vec<(Direction, u32)>,
+    next_move: usize,
+    steps_left: u32,
+    /// The position of each knot, starting with the head.
+    pub knots: Vec<Point<i32>>,
+    /// Every position the tail has visited.
+    pub visited: HashSet<Point<i32>>,
+}
+
+impl Rope {
+    /// A rope of `length` knots all at the origin, about to follow `moves`.
+    pub fn new(moves: &[(Direction, u32)], length: usize) -> Self {
+        let start = Point::new(0, 0);
+        Rope {
+            moves: moves.to_vec(),
+            next_move: 0,
+            steps_left: 0,
+            knots: vec![start; length],
+            visited: HashSet::from([start]),
+        }
+    }
+
+    /// The tail's trail, with the knots drawn over it.
+    pub fn picture(&self) -> Picture {
+        let palette = Palette::new([
+            Ink::new([16, 16, 32], '.'),
+            Ink::new([90, 140, 220], '#'),
+            Ink::new([250, 200, 60], 'H'),
+            Ink::new([240, 120, 60], 'o'),
+            Ink::new([240, 60, 60], 's'),
+        ]);
+        let to_i64 = |p: &Point<i32>| Point::new(p.x.into(), p.y.into());
+        let trail = self.visited.iter().map(|p| (to_i64(p), 1));
+        let start = std::iter::once((Point::new(0, 0), 4));
+        let knots = self
+            .knots
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, p)| (to_i64(p), if i == 0 { 2 } else { 3 }));
+        Picture::from_points(trail.chain(start).chain(knots), palette)
+    }
+}
+
+/// The progress through the moves and where the knots have been.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RopeState {
+    next_move: usize,
+    steps_left: u32,
+    knots: Vec<Point<i32>>,
+    visited: Vec<Point<i32>>,
+}
+
+impl Simulation for Rope {
+    type Snapshot = RopeState;
+
+    /// Move the head one step and let the other knots follow, finishing once the moves run out.
+    fn step(&mut self) -> Result<bool> {
+        if self.steps_left == 0 {
+            let Some(next) = (self.next_move..self.moves.len()).find(|&i| self.moves[i].1 > 0)
+            else {
+                return Ok(false);
+            };
+            self.steps_left = self.moves[next].1;
+            self.next_move = next + 1;
+        }
+        let (dir, _) = self.moves[self.next_move - 1];
+        let rope = &mut self.knots;
+        *rope.first_mut().context("getting head")? += dir.step();
+        for i in 1..rope.len() {
+            let gap = rope[i - 1] - rope[i];
+            match gap.chebyshev(Point::default()) {
+                0 | 1 => {}
+                2 => rope[i] += gap.signum(),
+                _ => bail!("planck length exceeded"),
+            }
+        }
+        self.visited.insert(*rope.last().context("getting tail")?);
+        self.steps_left -= 1;
+        Ok(true)
+    }
+
+    fn snapshot(&self) -> RopeState {
+        RopeState {
+            next_move: self.next_move,
+            steps_left: self.steps_left,
+            knots: self.knots.clone(),
+            visited: self.visited.iter().copied().sorted().collect(),
+        }
+    }
+
+    fn restore(&mut self, state: &RopeState) -> Result<()> {
+        // The steps left are of the move before `next_move`, so there are none before the first.
+        let current = state
+            .next_move
+            .checked_sub(1)
+            .and_then(|i| self.moves.get(i));
+        if state.next_move > self.moves.len()
+            || state.knots.len() != self.knots.len()
+            || state.steps_left > current.map_or(0, |&(_, steps)| steps)
+        {
+            bail!("The state is not of a rope following these moves");
+        }
+        self.next_move = state.next_move;
+        self.steps_left = state.steps_left;
+        self.knots = state.knots.clone();
+        self.visited = state.visited.iter().copied().collect();
+        Ok(())
+    }
+}
+
+impl Animation for Rope {
+    fn title(&self) -> String {
+        format!("Day 9: a rope of {} knots", self.knots.len())
+    }
+
+    fn picture(&self) -> Picture {
+        Rope::picture(self)
+    }
+
+    fn status(&self) -> String {
+        format!("Positions visited by the tail: {}", self.visited.len())
+    }
+
+    fn focus(&self) -> Option<Point<i64>> {
+        let head = self.knots.first()?;
+        Some(Point::new(head.x.into(), head.y.into()))
+    }
+}
+
+/// Every position the tail of a rope of `length` knots visits.
+pub fn tail_trail(moves: &[(Direction, u32)], length: usize) -> Result<HashSet<Point<i32>>> {
+    let mut rope = Rope::new(moves, length);
+    rope.run()?;
+    Ok(rope.visited)
+}
+
+/// The number of positions visited by the tail of a rope of `length` knots.
+pub fn tail_coverage(moves: &[(Direction, u32)], length: usize) -> Result<usize> {
+    Ok(tail_trail(moves, length)?.len())
+}
+
+/// The trails left by the tails of the short and long ropes, and where they started.
+pub fn draw_trails(moves: &[(Direction, u32)]) -> Result<Picture> {
+    let palette = Palette::new([
+        Ink::new([16, 16, 32], '.'),
+        Ink::new([90, 140, 220], '#'),
+        Ink::new([250, 200, 60], '@'),
+        Ink::new([240, 60, 60], 's'),
+    ]);
+    let to_i64 = |p: Point<i32>| Point::new(p.x.into(), p.y.into());
+    let short = tail_trail(moves, 2)?.into_iter().map(|p| (to_i64(p), 1));
+    let long = tail_trail(moves, 10)?.into_iter().map(|p| (to_i64(p), 2));
+    let start = std::iter::once((Point::new(0, 0), 3));
+    Picture::try_from_points(short.chain(long).chain(start), palette)
+}
+
+impl Solution for Day09 {
+    type Input = Vec<(Direction, u32)>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_moves(lines).context("reading moves")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(tail_coverage(input, 2)
+            .context("getting tail coverage")?
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(tail_coverage(input, 10)
+            .context("getting tail coverage")?
+            .into())
+    }
+
+    fn render(&self, input: &Self::Input) -> Result<Option<Picture>> {
+        Ok(Some(draw_trails(input)?))
+    }
+
+    fn simulation(&self, input: &Self::Input, part: Part) -> Result<Option<Box<dyn Animation>>> {
+        let length = if part == Part::One { 2 } else { 10 };
+        Ok(Some(Box::new(Rope::new(input, length))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    use proptest::prelude::*;
+    #[test]
+    fn test_day9() -> Result<()> {
+        assert_eq!(parse_move("R 4")?, (Direction::Right, 4));
+        assert_eq!(parse_move("U 56374")?, (Direction::Up, 56374));
+        let moves = parse_moves(fixture(9, "example")?)?;
+        assert_eq!(tail_coverage(&moves, 2)?, 13);
+        assert_eq!(tail_coverage(&moves, 10)?, 1);
+        assert_eq!(
+            draw_trails(&moves)?.to_ascii(),
+            "..##.\n...##\n.####\n....#\ns###."
+        );
+
+        let mut rope = Rope::new(&moves, 2);
+        let mut state = rope.snapshot();
+        state.steps_left = 1;
+        assert!(rope.restore(&state).is_err());
+        rope.step()?;
+        let mut state = rope.snapshot();
+        state.steps_left = 5;
+        assert!(rope.restore(&state).is_err());
+        state.steps_left = 3;
+        rope.restore(&state)?;
+
+        let moves = parse_moves(fixture(9, "larger")?)?;
+        assert_eq!(tail_coverage(&moves, 10)?, 36);
+        Ok(())
+    }
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop::sample::select(&Direction::ALL[..])
+    }
+
+    proptest! {
+        // A longer rope's tail can visit more cells than a shorter one's, so the tail is checked
+        // against the head's moves and against the knot before it rather than a shorter rope.
+        #[test]
+        fn tail_visits_no_more_cells_than_the_head_moves(
+            moves in prop::collection::vec((direction(), 1u32..10), 0..40),
+            length in 1usize..12,
+        ) {
+            let steps: u32 = moves.iter().map(|&(_, steps)| steps).sum();
+            let visits = tail_coverage(&moves, length)
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+            prop_assert!(visits <= steps as usize + 1);
+        }
+
+        #[test]
+        fn tail_stays_by_the_knot_before(
+            moves in prop::collection::vec((direction(), 1u32..10), 0..40),
+            length in 2usize..12,
+        ) {
+            // The knots behind knot `length - 2` do not pull on it, so it follows the same path
+            // as the tail of a rope one knot shorter.
+            let trail = |length| {
+                tail_trail(&moves, length).map_err(|e| TestCaseError::fail(e.to_string()))
+            };
+            let leader = trail(length - 1)?;
+            for cell in trail(length)? {
+                prop_assert!(
+                    cell.neighbours8().chain([cell]).any(|near| leader.contains(&near)),
+                    "{cell} is not next to the trail of the knot before"
+                );
+            }
+        }
+    }
+}
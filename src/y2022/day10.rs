@@ -0,0 +1,275 @@
+use anyhow::{bail, Context, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{cut, eof, map, value},
+    sequence::{preceded, terminated},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::grid::Grid;
+use crate::parse::{expect, hint, parse_line, parse_lines, signed, PResult};
+use crate::point::Point;
+use crate::render::{Ink, Palette, Picture};
+use crate::simulation::{Animation, Simulation};
+use crate::solution::{Answer, Part, Solution};
+
+/// Day 10: Cathode-Ray Tube.
+pub struct Day10;
+
+/// An instruction for the CPU.
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+fn instruction(s: &str) -> PResult<'_, Instruction> {
+    hint(
+        "expected `noop` or `addx` followed by a number",
+        expect(
+            ErrorKind::UnknownInstruction,
+            alt((
+                value(Instruction::Noop, tag("noop")),
+                map(
+                    preceded(
+                        // A bare `addx` is missing its number rather than unknown.
+                        alt((tag("addx "), terminated(tag("addx"), eof))),
+                        cut(hint("expected the amount to add to X", signed)),
+                    ),
+                    Instruction::AddX,
+                ),
+            )),
+        ),
+    )(s)
+}
+
+/// Read an instruction such as `addx 3`.
+pub fn parse_instruction(text: &str) -> Result<Instruction, ParseError> {
+    parse_line(instruction, text)
+}
+
+/// Read an instruction from each line.
+pub fn parse_program<I>(lines: I) -> Result<Vec<Instruction>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_lines(lines, instruction)
+}
+
+/// The CPU and the CRT it drives, run one cycle at a time.
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    program: Vec<Instruction>,
+    /// The index of the instruction being run.
+    pc: usize,
+    /// The cycles already spent on the instruction being run.
+    busy: u32,
+    /// The value in the X register, which is also the middle of the sprite.
+    pub x: i32,
+    /// The number of cycles completed.
+    pub cycle: i32,
+    /// The sum of the signal strengths seen so far.
+    pub signal_strength: i32,
+    /// The pixels lit on the CRT so far.
+    pub screen: Grid<bool>,
+}
+
+impl Cpu {
+    pub fn new(program: &[Instruction]) -> Self {
+        Cpu {
+            program: program.to_vec(),
+            pc: 0,
+            busy: 0,
+            x: 1,
+            cycle: 0,
+            signal_strength: 0,
+            screen: Grid::from_elem(6, 40, false),
+        }
+    }
+
+    /// The CRT, with lit pixels shown as `#` and dark ones as `.`, and while the program is
+    /// running, the sprite as `-` and the beam as `*`.
+    pub fn picture(&self) -> Picture {
+        let palette = Palette::new([
+            Ink::new([10, 10, 10], '.'),
+            Ink::new([80, 255, 80], '#'),
+            Ink::new([40, 70, 40], '-'),
+            Ink::new([255, 255, 255], '*'),
+        ]);
+        let mut picture = Picture::from_grid(&self.screen, palette, |&lit| lit.into());
+        if self.pc < self.program.len() {
+            let row = (self.cycle as usize / 40).min(5);
+            let x = i64::from(self.x);
+            let sprite = (x - 1..=x + 1).filter_map(|x| usize::try_from(x).ok());
+            let dark: Vec<_> = sprite
+                .map(|x| Point::new(x, row))
+                .filter(|&p| self.screen.get(p) == Some(&false))
+                .collect();
+            picture.paint(dark, 2);
+            picture.paint([Point::new(self.cycle as usize % 40, row)], 3);
+        }
+        picture
+    }
+}
+
+/// The progress through the program, the registers and what is on the CRT, with lit pixels as
+/// `#` and dark ones as `.`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CpuState {
+    pc: usize,
+    busy: u32,
+    x: i32,
+    cycle: i32,
+    signal_strength: i32,
+    screen: Vec<String>,
+}
+
+impl Simulation for Cpu {
+    type Snapshot = CpuState;
+
+    /// Run one cycle, drawing one pixel. Returns false once the program has finished.
+    fn step(&mut self) -> Result<bool> {
+        let Some(instruction) = self.program.get(self.pc) else {
+            return Ok(false);
+        };
+        self.cycle += 1;
+        if (self.cycle - 20) % 40 == 0 {
+            self.signal_strength = self
+                .cycle
+                .checked_mul(self.x)
+                .and_then(|strength| strength.checked_add(self.signal_strength))
+                .context("The signal strength is too large")?;
+        }
+
+        let pixel = (self.cycle - 1) as usize;
+        let h_pos = pixel % 40;
+        if (h_pos as i64 - i64::from(self.x)).abs() <= 1 {
+            if let Some(lit) = self.screen.get_mut(Point::new(h_pos, pixel / 40)) {
+                *lit = true;
+            }
+        }
+
+        self.busy += 1;
+        let num_cycles = match instruction {
+            Instruction::Noop => 1,
+            Instruction::AddX(_) => 2,
+        };
+        if self.busy == num_cycles {
+            if let Instruction::AddX(v) = instruction {
+                self.x = self
+                    .x
+                    .checked_add(*v)
+                    .context("The X register overflowed")?;
+            }
+            self.pc += 1;
+            self.busy = 0;
+        }
+        Ok(true)
+    }
+
+    fn snapshot(&self) -> CpuState {
+        let screen = self.screen.render(|&lit| if lit { '#' } else { '.' });
+        CpuState {
+            pc: self.pc,
+            busy: self.busy,
+            x: self.x,
+            cycle: self.cycle,
+            signal_strength: self.signal_strength,
+            screen: screen.lines().map(str::to_string).collect(),
+        }
+    }
+
+    fn restore(&mut self, state: &CpuState) -> Result<()> {
+        if state.pc > self.program.len() {
+            bail!("The state is not of a CPU running this program");
+        }
+        let screen = Grid::parse(&state.screen, |_, c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if (screen.rows(), screen.cols()) != (6, 40) {
+            bail!("The screen should be 40 by 6 pixels");
+        }
+        self.pc = state.pc;
+        self.busy = state.busy;
+        self.x = state.x;
+        self.cycle = state.cycle;
+        self.signal_strength = state.signal_strength;
+        self.screen = screen;
+        Ok(())
+    }
+}
+
+impl Animation for Cpu {
+    fn title(&self) -> String {
+        "Day 10: the CRT beam".to_string()
+    }
+
+    fn picture(&self) -> Picture {
+        Cpu::picture(self)
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "Cycle {}, X = {}, signal strength {}",
+            self.cycle, self.x, self.signal_strength
+        )
+    }
+}
+
+/// Run the program, returning the sum of the signal strengths and the image on the CRT.
+pub fn run_computer(program: &[Instruction]) -> Result<(i32, Picture)> {
+    let mut cpu = Cpu::new(program);
+    cpu.run()?;
+    Ok((cpu.signal_strength, cpu.picture()))
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_program(lines).context("reading program")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(run_computer(input)?.0.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(run_computer(input)?.1.to_ascii().into())
+    }
+
+    fn render(&self, input: &Self::Input) -> Result<Option<Picture>> {
+        Ok(Some(run_computer(input)?.1))
+    }
+
+    fn simulation(&self, input: &Self::Input, _part: Part) -> Result<Option<Box<dyn Animation>>> {
+        Ok(Some(Box::new(Cpu::new(input))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day10() -> Result<()> {
+        let bare = parse_instruction("addx").unwrap_err();
+        assert_eq!((bare.kind, bare.column), (ErrorKind::BadNumber, Some(5)));
+        let unknown = parse_instruction("addxy 1").unwrap_err();
+        assert_eq!(unknown.kind, ErrorKind::UnknownInstruction);
+
+        let expected_signal_strength = 13140;
+        let expected_screen = fixture(10, "screen")?.join("\n");
+        let (signal_strength, screen) = run_computer(&parse_program(fixture(10, "example")?)?)?;
+        assert_eq!(
+            (signal_strength, screen.to_ascii()),
+            (expected_signal_strength, expected_screen)
+        );
+        Ok(())
+    }
+}
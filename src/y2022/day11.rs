@@ -0,0 +1,398 @@
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, space0},
+    combinator::{cut, map, value, verify},
+    sequence::{delimited, pair, preceded, tuple},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::parse::{blocks, comma_list, expect, hint, unsigned, PResult};
+use crate::point::Point;
+use crate::render::{Ink, Palette, Picture};
+use crate::simulation::{Animation, Simulation};
+use crate::solution::{Answer, Part, Solution};
+
+/// Day 11: Monkey in the Middle.
+pub struct Day11;
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Multiply(u64),
+    Square,
+    Add(u64),
+}
+
+/// A monkey, along with the items it is holding.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    /// The worry level of each item held.
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    /// The divisor used to decide where to throw an item.
+    pub test: u64,
+    /// The monkeys to throw to when the test passes and when it fails.
+    pub target: (usize, usize),
+    pub items_inspected: u64,
+}
+
+fn operation(s: &str) -> PResult<'_, Operation> {
+    preceded(
+        hint(
+            "expected `Operation: new = old `",
+            pair(space0, tag("Operation: new = old ")),
+        ),
+        hint(
+            "expected `* old`, `* ` and a number, or `+ ` and a number",
+            expect(
+                ErrorKind::UnknownInstruction,
+                alt((
+                    value(Operation::Square, tag("* old")),
+                    map(preceded(tag("* "), cut(unsigned)), Operation::Multiply),
+                    map(preceded(tag("+ "), cut(unsigned)), Operation::Add),
+                )),
+            ),
+        ),
+    )(s)
+}
+
+fn starting_items(s: &str) -> PResult<'_, Vec<u64>> {
+    preceded(
+        hint(
+            "expected `Starting items: `",
+            pair(space0, tag("Starting items: ")),
+        ),
+        hint(
+            "expected the worry level of each item, separated by commas",
+            comma_list(unsigned),
+        ),
+    )(s)
+}
+
+fn test(s: &str) -> PResult<'_, u64> {
+    preceded(
+        hint(
+            "expected `Test: divisible by `",
+            pair(space0, tag("Test: divisible by ")),
+        ),
+        hint(
+            "expected a number above zero to divide by",
+            expect(ErrorKind::BadNumber, verify(unsigned, |&n: &u64| n > 0)),
+        ),
+    )(s)
+}
+
+fn target(outcome: &'static str) -> impl FnMut(&str) -> PResult<'_, usize> {
+    move |s| {
+        preceded(
+            hint(
+                "expected `If true: throw to monkey ` or `If false: ...` in that order",
+                tuple((space0, tag("If "), tag(outcome), tag(": throw to monkey "))),
+            ),
+            hint("expected the number of the monkey to throw to", unsigned),
+        )(s)
+    }
+}
+
+/// Read the notes on each monkey.
+pub fn parse_monkeys<I>(lines: I) -> Result<Vec<Monkey>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut monkeys = vec![];
+    for block in blocks(lines) {
+        if let Some(extra) = block.lines.get(6) {
+            let indent = extra.chars().take_while(|c| c.is_whitespace()).count();
+            return Err(ParseError::new(ErrorKind::Unexpected, extra.trim())
+                .at_line(block.first_line + 6)
+                .at_column(indent + 1)
+                .with_source(extra)
+                .with_hint("expected a blank line before the next monkey"));
+        }
+        block.parse_line(
+            0,
+            hint(
+                "expected a heading like `Monkey 0:`",
+                delimited(tag("Monkey "), unsigned::<usize>, char(':')),
+            ),
+        )?;
+        let monkey = Monkey {
+            items: block.parse_line(1, starting_items)?,
+            operation: block.parse_line(2, operation)?,
+            test: block.parse_line(3, test)?,
+            target: (
+                block.parse_line(4, target("true"))?,
+                block.parse_line(5, target("false"))?,
+            ),
+            items_inspected: 0,
+        };
+        monkeys.push(monkey);
+    }
+    Ok(monkeys)
+}
+
+/// One item thrown from one monkey to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Throw {
+    pub from: usize,
+    pub to: usize,
+    /// The worry level of the item once it has been inspected.
+    pub worry: u64,
+}
+
+/// The monkeys partway through their game, played one throw at a time.
+#[derive(Debug, Clone)]
+pub struct Troop {
+    pub monkeys: Vec<Monkey>,
+    relief: bool,
+    rounds: u32,
+    /// The product of the divisors, or `None` if it is too large to keep worry levels below.
+    moderator: Option<u64>,
+    /// The number of rounds completed.
+    pub round: u32,
+    /// The monkey whose turn it is.
+    pub turn: usize,
+    /// How many of the items the monkey had at the start of its turn are still to be thrown.
+    left: usize,
+    /// The throw made by the last step, if it made one.
+    pub last_throw: Option<Throw>,
+}
+
+impl Troop {
+    /// The monkeys about to play `rounds` rounds, with worry levels divided by three after each
+    /// inspection if there is `relief`.
+    pub fn new(monkeys: &[Monkey], relief: bool, rounds: u32) -> Self {
+        Troop {
+            monkeys: monkeys.to_vec(),
+            relief,
+            rounds,
+            moderator: monkeys
+                .iter()
+                .try_fold(1u64, |product, m| product.checked_mul(m.test)),
+            round: 0,
+            turn: 0,
+            left: monkeys.first().map_or(0, |m| m.items.len()),
+            last_throw: None,
+        }
+    }
+
+    /// The product of the number of items inspected by the two most active monkeys.
+    pub fn monkey_business(&self) -> u64 {
+        self.monkeys
+            .iter()
+            .map(|m| m.items_inspected)
+            .sorted()
+            .rev()
+            .take(2)
+            .product()
+    }
+
+    /// A row for each monkey, headed by the monkey and then the items it holds, with the monkey
+    /// whose turn it is and the item just thrown picked out.
+    pub fn picture(&self) -> Picture {
+        let palette = Palette::new([
+            Ink::new([15, 15, 20], ' '),
+            Ink::new([160, 110, 60], 'M'),
+            Ink::new([255, 220, 80], 'M'),
+            Ink::new([120, 180, 120], 'o'),
+            Ink::new([255, 90, 90], '*'),
+        ]);
+        let mut points = vec![];
+        for (m, monkey) in self.monkeys.iter().enumerate() {
+            let y = m as i64;
+            points.push((Point::new(0, y), if m == self.turn { 2 } else { 1 }));
+            points.push((Point::new(1, y), 0));
+            for i in 0..monkey.items.len() {
+                points.push((Point::new(i as i64 + 2, y), 3));
+            }
+        }
+        if let Some(Throw { to, .. }) = self.last_throw {
+            let x = self.monkeys[to].items.len() as i64 + 1;
+            points.push((Point::new(x, to as i64), 4));
+        }
+        Picture::from_points(points, palette)
+    }
+}
+
+/// The items each monkey holds and has inspected, and how far through the game they are.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TroopState {
+    items: Vec<Vec<u64>>,
+    inspected: Vec<u64>,
+    round: u32,
+    turn: usize,
+    left: usize,
+}
+
+impl Simulation for Troop {
+    type Snapshot = TroopState;
+
+    /// Throw the next item, or pass the turn on to the next monkey if this one has thrown all
+    /// that it started its turn with, finishing once every round has been played.
+    fn step(&mut self) -> Result<bool> {
+        if self.round >= self.rounds || self.monkeys.is_empty() {
+            return Ok(false);
+        }
+        self.last_throw = None;
+        if self.left == 0 {
+            self.turn += 1;
+            if self.turn == self.monkeys.len() {
+                self.turn = 0;
+                self.round += 1;
+            }
+            self.left = self.monkeys[self.turn].items.len();
+            return Ok(true);
+        }
+
+        let moderator = self
+            .moderator
+            .context("The product of the monkeys' divisors is too large")?;
+        let monkey = &mut self.monkeys[self.turn];
+        // Worry levels fit in a u64, so one operation on one cannot overflow a u128.
+        let mut worry = u128::from(monkey.items.remove(0));
+        match monkey.operation {
+            Operation::Multiply(x) => worry *= u128::from(x),
+            Operation::Square => worry *= worry,
+            Operation::Add(x) => worry += u128::from(x),
+        }
+        if self.relief {
+            worry /= 3;
+        }
+        let worry = (worry % u128::from(moderator)) as u64;
+        monkey.items_inspected += 1;
+        self.left -= 1;
+
+        let to = if worry.is_multiple_of(monkey.test) {
+            monkey.target.0
+        } else {
+            monkey.target.1
+        };
+        self.monkeys
+            .get_mut(to)
+            .with_context(|| format!("Monkey {} throws to missing monkey {to}", self.turn))?
+            .items
+            .push(worry);
+        self.last_throw = Some(Throw {
+            from: self.turn,
+            to,
+            worry,
+        });
+        Ok(true)
+    }
+
+    fn snapshot(&self) -> TroopState {
+        TroopState {
+            items: self.monkeys.iter().map(|m| m.items.clone()).collect(),
+            inspected: self.monkeys.iter().map(|m| m.items_inspected).collect(),
+            round: self.round,
+            turn: self.turn,
+            left: self.left,
+        }
+    }
+
+    fn restore(&mut self, state: &TroopState) -> Result<()> {
+        let count = self.monkeys.len();
+        if state.items.len() != count || state.inspected.len() != count {
+            bail!("The state is not of a troop of {count} monkeys");
+        }
+        if state.turn >= count.max(1) || state.left > state.items[state.turn].len() {
+            bail!(
+                "Monkey {} cannot have {} items left to throw",
+                state.turn,
+                state.left
+            );
+        }
+        for (monkey, (items, &inspected)) in self
+            .monkeys
+            .iter_mut()
+            .zip(state.items.iter().zip(&state.inspected))
+        {
+            monkey.items = items.clone();
+            monkey.items_inspected = inspected;
+        }
+        self.round = state.round;
+        self.turn = state.turn;
+        self.left = state.left;
+        self.last_throw = None;
+        Ok(())
+    }
+}
+
+impl Animation for Troop {
+    fn title(&self) -> String {
+        "Day 11: monkeys throwing items".to_string()
+    }
+
+    fn picture(&self) -> Picture {
+        Troop::picture(self)
+    }
+
+    fn status(&self) -> String {
+        let inspected: Vec<_> = self
+            .monkeys
+            .iter()
+            .map(|m| m.items_inspected.to_string())
+            .collect();
+        format!(
+            "Round {}, monkey {}'s turn, inspected {}",
+            self.round + 1,
+            self.turn,
+            inspected.join("/")
+        )
+    }
+}
+
+/// The product of the number of items inspected by the two most active monkeys after `rounds`
+/// rounds, with worry levels divided by three after each inspection if there is `relief`.
+pub fn monkey_business(monkeys: &[Monkey], relief: bool, rounds: u32) -> Result<u64> {
+    let mut troop = Troop::new(monkeys, relief, rounds);
+    troop.run()?;
+    Ok(troop.monkey_business())
+}
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_monkeys(lines).context("Reading monkey notes")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(monkey_business(input, true, 20)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(monkey_business(input, false, 10000)?.into())
+    }
+
+    fn simulation(&self, input: &Self::Input, part: Part) -> Result<Option<Box<dyn Animation>>> {
+        Ok(Some(Box::new(match part {
+            Part::One => Troop::new(input, true, 20),
+            Part::Two => Troop::new(input, false, 10000),
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day11() -> Result<()> {
+        let monkeys = parse_monkeys(fixture(11, "example")?)?;
+        assert_eq!(monkey_business(&monkeys, true, 20)?, 10605);
+        assert_eq!(monkey_business(&monkeys, false, 10000)?, 2713310158);
+
+        let mut notes = fixture(11, "example")?;
+        notes.insert(6, "  Also: nothing".to_string());
+        let error = parse_monkeys(notes).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(7), Some(3)));
+        assert_eq!(error.source.as_deref(), Some("  Also: nothing"));
+        Ok(())
+    }
+}
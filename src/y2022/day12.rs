@@ -0,0 +1,136 @@
+use anyhow::{Context, Result};
+use pathfinding::directed::bfs::bfs;
+
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::render::{Ink, Palette, Picture};
+use crate::solution::{Answer, Solution};
+
+/// Day 12: Hill Climbing Algorithm.
+pub struct Day12;
+
+/// The elevation of each square, from 0 for `a` to 25 for `z`.
+pub type Heightmap = Grid<u8>;
+
+/// Read the heightmap along with the start and end positions.
+pub fn lines_to_grid<I>(lines: I) -> Result<(Heightmap, Pos, Pos), ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut start = None;
+    let mut end = None;
+    let heightmap = Grid::parse(lines, |pos, d| match d {
+        'a'..='z' => Some(d as u8 - b'a'),
+        'S' => {
+            start = Some(pos);
+            Some(0)
+        }
+        'E' => {
+            end = Some(pos);
+            Some(b'z' - b'a')
+        }
+        _ => None,
+    })?;
+
+    Ok((
+        heightmap,
+        start.ok_or(ParseError::missing("start `S`"))?,
+        end.ok_or(ParseError::missing("end `E`"))?,
+    ))
+}
+
+/// The shortest path from `start` to `end`, climbing at most one level at each step.
+pub fn find_path(graph: &Heightmap, start: Pos, end: Pos) -> Option<Vec<Pos>> {
+    bfs(
+        &start,
+        |&p| {
+            let h = graph[p];
+            graph
+                .neighbours4(p)
+                .filter(|&n| graph[n] <= h + 1)
+                .collect::<Vec<_>>()
+        },
+        |p| *p == end,
+    )
+}
+
+/// The fewest steps to `end` from any square at `height`.
+pub fn find_shortest_from_height(heightmap: &Heightmap, height: u32, end: Pos) -> Result<usize> {
+    heightmap
+        .indexed_iter()
+        .filter_map(|(c, h)| {
+            if *h as u32 == height {
+                find_path(heightmap, c, end)
+            } else {
+                None
+            }
+        })
+        .map(|path| path.len() - 1)
+        .min()
+        .context("finding shortest start")
+}
+
+/// The heightmap shaded from low to high, with the shortest path from the start drawn over it.
+pub fn draw_route(heightmap: &Heightmap, start: Pos, end: Pos) -> Picture {
+    let glyphs: String = ('a'..='z').collect();
+    let palette = Palette::gradient([30, 70, 30], [235, 235, 220], &glyphs)
+        .with(Ink::new([230, 40, 40], '#'));
+    let mut picture = Picture::from_grid(heightmap, palette, |&h| h);
+    picture.paint(find_path(heightmap, start, end).unwrap_or_default(), 26);
+    picture
+}
+
+impl Solution for Day12 {
+    type Input = (Heightmap, Pos, Pos);
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        lines_to_grid(lines).context("reading heightmap")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let (heightmap, start, end) = input;
+        let start_to_end = find_path(heightmap, *start, *end).context("finding primary path")?;
+        Ok((start_to_end.len() - 1).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let (heightmap, _, end) = input;
+        Ok(find_shortest_from_height(heightmap, 0, *end)?.into())
+    }
+
+    fn render(&self, input: &Self::Input) -> Result<Option<Picture>> {
+        let (heightmap, start, end) = input;
+        Ok(Some(draw_route(heightmap, *start, *end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day12() -> Result<()> {
+        let (heightmap, start, end) = lines_to_grid(fixture(12, "example")?)?;
+        assert_eq!(start, Point::new(0, 0));
+        assert_eq!(end, Point::new(5, 2));
+        assert_eq!(
+            find_path(&heightmap, start, end)
+                .context("running test")?
+                .len()
+                - 1,
+            31
+        );
+        assert_eq!(find_shortest_from_height(&heightmap, 0, end)?, 29);
+        assert_eq!(
+            draw_route(&heightmap, start, end)
+                .to_ascii()
+                .matches('#')
+                .count(),
+            32
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, multispace0},
+    combinator::map,
+    multi::separated_list0,
+    sequence::delimited,
+};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::parse::{blocks, hint, parse_line, unsigned, PResult};
+use crate::solution::{Answer, Solution};
+
+/// Day 13: Distress Signal.
+pub struct Day13;
+
+/// A packet: either an integer or a list of packets.
+#[derive(Debug, Clone)]
+pub enum Message {
+    Num(u32),
+    List(Vec<Message>),
+}
+
+impl FromStr for Message {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse_line(parse_list, s)
+    }
+}
+
+/// Written as in the puzzle input, like `[1,[2,3]]`.
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Num(n) => write!(f, "{n}"),
+            Message::List(items) => write!(f, "[{}]", items.iter().join(",")),
+        }
+    }
+}
+
+impl Ord for Message {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Message::Num(a), Message::Num(b)) => a.cmp(b),
+            (Message::Num(a), Message::List(b)) => vec![Message::Num(*a)].cmp(b),
+            (Message::List(a), Message::Num(b)) => a.cmp(&vec![Message::Num(*b)]),
+            (Message::List(a), Message::List(b)) => a.cmp(b),
+        }
+    }
+}
+
+/// Packets are equal when neither comes before the other, so `[1]` equals `[[1]]`.
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Message {}
+
+impl PartialOrd for Message {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_list_entries(s: &str) -> PResult<'_, Message> {
+    let (remaining, list) = separated_list0(
+        delimited(multispace0, char(','), multispace0),
+        alt((parse_number, parse_list)),
+    )(s)?;
+    Ok((remaining, Message::List(list)))
+}
+
+fn parse_list(s: &str) -> PResult<'_, Message> {
+    delimited(
+        hint(
+            "expected a packet, which is a list like `[1,[2,3]]`",
+            tag("["),
+        ),
+        parse_list_entries,
+        hint(
+            "expected `,` and another entry, or `]` to end the list",
+            tag("]"),
+        ),
+    )(s)
+}
+
+fn parse_number(s: &str) -> PResult<'_, Message> {
+    map(unsigned, Message::Num)(s)
+}
+
+/// Read the pairs of packets, which are separated by blank lines.
+pub fn parse_pairs<I>(lines: I) -> Result<Vec<(Message, Message)>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    blocks(lines)
+        .iter()
+        .map(|block| {
+            if let Some(extra) = block.lines.get(2) {
+                return Err(ParseError::new(ErrorKind::Unexpected, extra)
+                    .at_line(block.first_line + 2)
+                    .at_column(1)
+                    .with_source(extra)
+                    .with_hint("expected a blank line after each pair of packets"));
+            }
+            Ok((
+                block.parse_line(0, parse_list)?,
+                block.parse_line(1, parse_list)?,
+            ))
+        })
+        .collect()
+}
+
+/// The sum of the (1-based) indices of the pairs that are in the right order.
+pub fn check_message(pairs: &[(Message, Message)]) -> usize {
+    let mut index_sum = 0;
+    for (i, (a, b)) in pairs.iter().enumerate() {
+        if a < b {
+            index_sum += i + 1;
+        }
+    }
+    index_sum
+}
+
+/// The decoder key: the product of the positions of the divider packets once all are sorted.
+pub fn sort_messages(pairs: &[(Message, Message)]) -> Result<usize> {
+    let dividers: [Message; 2] = ["[[2]]".parse()?, "[[6]]".parse()?];
+    let packets: Vec<_> = pairs.iter().flat_map(|(a, b)| [a, b]).collect();
+    Ok(dividers
+        .iter()
+        .enumerate()
+        .map(|(i, divider)| i + 1 + packets.iter().filter(|&&p| p < divider).count())
+        .product())
+}
+
+impl Solution for Day13 {
+    type Input = Vec<(Message, Message)>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_pairs(lines).context("reading packets")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(check_message(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(sort_messages(input).context("sorting messages")?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    use proptest::prelude::*;
+    #[test]
+    fn test_day13() -> Result<()> {
+        assert_eq!(parse_number("1")?.1, Message::Num(1));
+        assert_eq!(parse_number("12")?.1, Message::Num(12));
+        assert_eq!(
+            parse_list_entries("1,2,3")?.1,
+            Message::List(vec![Message::Num(1), Message::Num(2), Message::Num(3)])
+        );
+        assert_eq!(
+            parse_list_entries("1, 2, 3")?.1,
+            Message::List(vec![Message::Num(1), Message::Num(2), Message::Num(3)])
+        );
+        assert_eq!(
+            parse_list("[1,2,3]")?.1,
+            Message::List(vec![Message::Num(1), Message::Num(2), Message::Num(3)])
+        );
+        assert_eq!(
+            parse_list("[1,[2]]")?.1,
+            Message::List(vec![Message::Num(1), Message::List(vec![Message::Num(2)])])
+        );
+        assert_eq!(
+            "[1,[2]]".parse::<Message>()?,
+            Message::List(vec![Message::Num(1), Message::List(vec![Message::Num(2)])])
+        );
+
+        assert!("[1,1,3,1,1]".parse::<Message>()? < "[1,1,5,1,1]".parse::<Message>()?);
+        assert!("[[1],[2,3,4]]".parse::<Message>()? < "[[1],4]".parse::<Message>()?);
+        assert!("[9]".parse::<Message>()? > "[[8,7,6]]".parse::<Message>()?);
+        assert!("[[4,4],4,4]".parse::<Message>()? < "[[4,4],4,4,4]".parse::<Message>()?);
+        assert!("[7,7,7,7]".parse::<Message>()? > "[7,7,7]".parse::<Message>()?);
+        assert!("[]".parse::<Message>()? < "[3]".parse::<Message>()?);
+        assert!("[[[]]]".parse::<Message>()? > "[[]]".parse::<Message>()?);
+        assert!(
+            "[1,[2,[3,[4,[5,6,7]]]],8,9]".parse::<Message>()?
+                > "[1,[2,[3,[4,[5,6,0]]]],8,9]".parse::<Message>()?
+        );
+
+        let pairs = parse_pairs(fixture(13, "example")?)?;
+        assert_eq!(check_message(&pairs), 13);
+        assert_eq!(sort_messages(&pairs)?, 140);
+        assert_eq!("[1]".parse::<Message>()?, "[[1]]".parse::<Message>()?);
+        assert_eq!(Message::Num(1), "[1]".parse::<Message>()?);
+        assert_ne!("[1]".parse::<Message>()?, "[1,1]".parse::<Message>()?);
+        // A packet like a divider in the input is not taken for one.
+        let lookalike = [("[[2]]".parse()?, "[1]".parse()?)];
+        assert_eq!(sort_messages(&lookalike)?, 2 * 4);
+        assert_eq!(
+            parse_pairs(["[1]", "[2]", "[3]"]),
+            Err(ParseError::new(ErrorKind::Unexpected, "[3]")
+                .at_line(3)
+                .at_column(1)
+                .with_source("[3]")
+                .with_hint("expected a blank line after each pair of packets"))
+        );
+
+        Ok(())
+    }
+
+    /// Packets with few and small numbers, so that equal and nearly equal ones are common.
+    fn packet() -> impl Strategy<Value = Message> {
+        let leaf = (0u32..4).prop_map(Message::Num);
+        let message = leaf.prop_recursive(4, 24, 4, |inner| {
+            prop::collection::vec(inner, 0..4).prop_map(Message::List)
+        });
+        prop::collection::vec(message, 0..4).prop_map(Message::List)
+    }
+
+    proptest! {
+        #[test]
+        fn order_is_total(a in packet(), b in packet(), c in packet()) {
+            prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+            prop_assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+        }
+
+        #[test]
+        fn display_round_trips(a in packet()) {
+            let parsed = a.to_string().parse::<Message>()?;
+            prop_assert_eq!(format!("{parsed:?}"), format!("{a:?}"));
+        }
+    }
+}
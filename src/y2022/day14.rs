@@ -0,0 +1,307 @@
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{cut, map, verify},
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::grid::{Grid, Pos};
+use crate::parse::{expect, hint, parse_lines, unsigned, PResult};
+use crate::point::Point;
+use crate::render::{Ink, Palette, Picture};
+use crate::simulation::{Animation, Simulation};
+use crate::solution::{Answer, Part, Solution};
+
+/// Day 14: Regolith Reservoir.
+pub struct Day14;
+
+#[derive(Clone, Debug, PartialEq)]
+enum C {
+    Empty,
+    Wall,
+    Sand,
+}
+
+/// A path of rock, as a list of corners.
+pub type Path = Vec<Pos>;
+
+/// The largest coordinate allowed in the scan, which keeps the cave small enough to hold in
+/// memory.
+pub const MAX_COORDINATE: usize = 2000;
+
+fn coordinate(s: &str) -> PResult<'_, usize> {
+    hint(
+        "expected a coordinate no larger than 2000",
+        expect(
+            ErrorKind::BadNumber,
+            verify(unsigned, |&n: &usize| n <= MAX_COORDINATE),
+        ),
+    )(s)
+}
+
+fn corner(s: &str) -> PResult<'_, Pos> {
+    map(
+        separated_pair(coordinate, char(','), coordinate),
+        |(x, y)| Point::new(x, y),
+    )(s)
+}
+
+fn rock_path(s: &str) -> PResult<'_, Path> {
+    separated_list1(tag(" -> "), cut(corner))(s)
+}
+
+/// Read the paths of rock in the scan.
+pub fn parse_paths<I>(lines: I) -> Result<Vec<Path>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_lines(lines, rock_path)
+}
+
+/// Draw the rock paths into a grid tall enough for the floor and wide enough on both sides for
+/// sand to pile up against it, returning the grid, the lowest row of rock and where the source
+/// is in the grid, which is shifted right if the pile would reach past `x = 0`.
+fn build_cave(paths: &[Path], floor: bool) -> (Grid<C>, usize, Pos) {
+    let lowest_point = paths.iter().flatten().map(|p| p.y).max().unwrap_or(0);
+    let rightmost = paths.iter().flatten().map(|p| p.x).max().unwrap_or(0);
+    let rows = lowest_point + 3;
+    let shift = rows.saturating_sub(SOURCE.x);
+    let cols = rightmost.max(SOURCE.x + rows) + 2 + shift;
+    let mut grid = Grid::from_elem(rows, cols, C::Empty);
+    for path in paths {
+        for (start, stop) in path.iter().tuple_windows() {
+            for y in start.y.min(stop.y)..=start.y.max(stop.y) {
+                for x in start.x.min(stop.x)..=start.x.max(stop.x) {
+                    grid[Point::new(x + shift, y)] = C::Wall;
+                }
+            }
+        }
+    }
+
+    if floor {
+        grid.fill_row(lowest_point + 2, C::Wall);
+    }
+    (grid, lowest_point, Point::new(SOURCE.x + shift, SOURCE.y))
+}
+
+/// Where the sand pours in.
+const SOURCE: Pos = Point::new(500, 0);
+
+/// The cave as sand pours into it, one move of one grain at a time.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    grid: Grid<C>,
+    lowest_point: usize,
+    floor: bool,
+    /// Where the sand pours in, in the grid.
+    source: Pos,
+    /// The grain that is falling, if there is one.
+    pub grain: Option<Pos>,
+    /// The number of grains that have come to rest.
+    pub rested: u32,
+}
+
+impl Cave {
+    /// The cave with no sand in it yet, with or without a floor.
+    pub fn new(paths: &[Path], floor: bool) -> Self {
+        let (grid, lowest_point, source) = build_cave(paths, floor);
+        Cave {
+            grid,
+            lowest_point,
+            floor,
+            source,
+            grain: None,
+            rested: 0,
+        }
+    }
+
+    /// The cave cropped to the rock and sand, with any falling grain.
+    pub fn picture(&self) -> Picture {
+        let floor = self.lowest_point + 2;
+        let palette = Palette::new([
+            Ink::new([20, 20, 30], '.'),
+            Ink::new([120, 110, 100], '#'),
+            Ink::new([230, 190, 90], 'o'),
+            Ink::new([255, 240, 200], '~'),
+            Ink::new([90, 200, 255], '+'),
+        ]);
+        let to_i64 = |pos: Pos| Point::new(pos.x as i64, pos.y as i64);
+        let filled: Vec<_> = self
+            .grid
+            .indexed_iter()
+            .filter(|&(pos, c)| pos.y < floor && *c != C::Empty)
+            .map(|(pos, c)| (to_i64(pos), if *c == C::Wall { 1 } else { 2 }))
+            .chain(
+                std::iter::once((to_i64(self.source), 4))
+                    .filter(|_| self.grid[self.source] == C::Empty),
+            )
+            .chain(self.grain.map(|g| (to_i64(g), 3)))
+            .collect();
+        let left = filled.iter().map(|(p, _)| p.x).min().unwrap_or(0) - 1;
+        let right = filled.iter().map(|(p, _)| p.x).max().unwrap_or(0) + 1;
+        let floor = (left..=right)
+            .filter(|_| self.floor)
+            .map(|x| (Point::new(x, floor as i64), 1));
+        Picture::from_points(filled.into_iter().chain(floor), palette)
+    }
+}
+
+/// The sand that has come to rest and the grain that is falling, as positions in the cave's grid.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaveState {
+    sand: Vec<Pos>,
+    grain: Option<Pos>,
+    rested: u32,
+}
+
+impl Simulation for Cave {
+    type Snapshot = CaveState;
+
+    /// Move the falling grain one step, or let a new grain in at the source. Finishes once sand
+    /// starts falling into the abyss or, if there is a floor, the source is blocked.
+    fn step(&mut self) -> Result<bool> {
+        let Some(s) = self.grain else {
+            if self.grid[self.source] == C::Sand {
+                return Ok(false);
+            }
+            self.grain = Some(self.source);
+            return Ok(true);
+        };
+        if !self.floor && s.y > self.lowest_point {
+            return Ok(false);
+        }
+        let next = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)]
+            .into_iter()
+            .map(|step| self.grid.offset(s, step))
+            .find(|p| p.is_none_or(|p| self.grid[p] == C::Empty));
+        match next {
+            Some(Some(p)) => self.grain = Some(p),
+            // Falling off the side of the grid means falling forever.
+            Some(None) => return Ok(false),
+            None => {
+                self.grid[s] = C::Sand;
+                self.grain = None;
+                self.rested += 1;
+            }
+        }
+        Ok(true)
+    }
+
+    fn snapshot(&self) -> CaveState {
+        CaveState {
+            sand: self
+                .grid
+                .indexed_iter()
+                .filter(|&(_, c)| *c == C::Sand)
+                .map(|(pos, _)| pos)
+                .collect(),
+            grain: self.grain,
+            rested: self.rested,
+        }
+    }
+
+    fn restore(&mut self, state: &CaveState) -> Result<()> {
+        if let Some(&pos) = state
+            .sand
+            .iter()
+            .chain(&state.grain)
+            .find(|&&p| self.grid.get(p).is_none_or(|c| *c == C::Wall))
+        {
+            bail!("There is no room for sand at {pos}");
+        }
+        for pos in self.snapshot().sand {
+            self.grid[pos] = C::Empty;
+        }
+        for &pos in &state.sand {
+            self.grid[pos] = C::Sand;
+        }
+        self.grain = state.grain;
+        self.rested = state.rested;
+        Ok(())
+    }
+}
+
+impl Animation for Cave {
+    fn title(&self) -> String {
+        "Day 14: falling sand".to_string()
+    }
+
+    fn picture(&self) -> Picture {
+        Cave::picture(self)
+    }
+
+    fn status(&self) -> String {
+        format!("Grains at rest: {}", self.rested)
+    }
+
+    fn focus(&self) -> Option<Point<i64>> {
+        self.grain.map(|g| Point::new(g.x as i64, g.y as i64))
+    }
+}
+
+/// Count the grains of sand that come to rest, either before they start falling into the abyss
+/// or, if there is a floor, before the source is blocked.
+pub fn run_sand(paths: &[Path], floor: bool) -> Result<u32> {
+    let mut cave = Cave::new(paths, floor);
+    cave.run()?;
+    Ok(cave.rested)
+}
+
+/// The cave once the sand has stopped piling up on the floor.
+pub fn draw_cave(paths: &[Path]) -> Result<Picture> {
+    let mut cave = Cave::new(paths, true);
+    cave.run()?;
+    Ok(cave.picture())
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Path>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_paths(lines).context("Reading rock paths")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(run_sand(input, false)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(run_sand(input, true)?.into())
+    }
+
+    fn render(&self, input: &Self::Input) -> Result<Option<Picture>> {
+        Ok(Some(draw_cave(input)?))
+    }
+
+    fn simulation(&self, input: &Self::Input, part: Part) -> Result<Option<Box<dyn Animation>>> {
+        Ok(Some(Box::new(Cave::new(input, part == Part::Two))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    #[test]
+    fn test_day14() -> Result<()> {
+        let paths = parse_paths(fixture(14, "example")?)?;
+        assert_eq!(run_sand(&paths, false)?, 24);
+        assert_eq!(run_sand(&paths, true)?, 93);
+        assert_eq!(draw_cave(&paths)?.to_ascii().matches('o').count(), 93);
+
+        // A staircase of rock leads the first grain down to the floor left of `x = 0`.
+        let stairs = (0..=500).map(|k| format!("{0},{1} -> {0},{1}", 500 - k, k + 1));
+        let mut cave = Cave::new(&parse_paths(stairs)?, true);
+        while cave.rested == 0 {
+            assert!(cave.step()?);
+        }
+        assert!(parse_paths(["500,2001 -> 500,0"]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use nom::{
+    bytes::complete::tag,
+    combinator::{map, verify},
+    sequence::{pair, preceded, separated_pair},
+};
+
+use crate::error::{ErrorKind, ParseError};
+use crate::parse::{expect, hint, parse_line, parse_lines, signed, PResult};
+use crate::point::Point;
+use crate::render::{Ink, Palette, Picture};
+use crate::solution::{Answer, Solution};
+
+/// Day 15: Beacon Exclusion Zone.
+pub struct Day15;
+
+/// A sensor and the position of the closest beacon to it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Reading {
+    pub sensor: Point<i64>,
+    pub beacon: Point<i64>,
+}
+
+impl Reading {
+    /// The Manhattan distance from the sensor to its beacon.
+    pub fn distance(&self) -> i64 {
+        self.sensor.manhattan(self.beacon)
+    }
+
+    /// The range of `x` in `row` that is no further from the sensor than its beacon.
+    pub fn row_coverage(&self, row: i64) -> std::ops::RangeInclusive<i64> {
+        let d = self.distance();
+        let dy = (row - self.sensor.y).abs();
+        let dx = d - dy;
+        (self.sensor.x - dx)..=(self.sensor.x + dx)
+    }
+}
+
+/// The largest distance from the origin allowed for either coordinate of a position, which keeps
+/// the distances between them well within range.
+pub const MAX_COORDINATE: i64 = 1 << 31;
+
+fn bounded(s: &str) -> PResult<'_, i64> {
+    hint(
+        "expected a coordinate between -2147483648 and 2147483648",
+        expect(
+            ErrorKind::BadNumber,
+            verify(signed, |n: &i64| n.abs() <= MAX_COORDINATE),
+        ),
+    )(s)
+}
+
+fn position(s: &str) -> PResult<'_, Point<i64>> {
+    map(
+        separated_pair(
+            preceded(tag("x="), bounded),
+            tag(", "),
+            preceded(tag("y="), bounded),
+        ),
+        |(x, y)| Point::new(x, y),
+    )(s)
+}
+
+fn reading(s: &str) -> PResult<'_, Reading> {
+    map(
+        pair(
+            preceded(
+                hint("expected `Sensor at `", tag("Sensor at ")),
+                hint("expected the sensor's position, like `x=2, y=18`", position),
+            ),
+            preceded(
+                hint(
+                    "expected `: closest beacon is at `",
+                    tag(": closest beacon is at "),
+                ),
+                hint(
+                    "expected the beacon's position, like `x=-2, y=15`",
+                    position,
+                ),
+            ),
+        ),
+        |(sensor, beacon)| Reading { sensor, beacon },
+    )(s)
+}
+
+impl FromStr for Reading {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_line(reading, s)
+    }
+}
+
+/// Read a sensor reading from each line.
+pub fn parse_readings<I>(lines: I) -> Result<Vec<Reading>, ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_lines(lines, reading)
+}
+
+/// The number of positions within `range` on row `check` that cannot contain a beacon.
+pub fn row_coverage(
+    readings: &[Reading],
+    check: i64,
+    range: std::ops::RangeInclusive<i64>,
+) -> usize {
+    let beacons_in_row: HashSet<_> = readings
+        .iter()
+        .filter_map(|r| {
+            if r.beacon.y == check {
+                Some(r.beacon.x)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let covered: Vec<_> = readings.iter().map(|r| r.row_coverage(check)).collect();
+
+    let mut count = 0;
+    for x in range {
+        if covered.iter().any(|r| r.contains(&x)) && !beacons_in_row.contains(&x) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// The tuning frequency of the only position within `range` in both directions that no sensor
+/// covers.
+pub fn find_gap(readings: &[Reading], range: std::ops::RangeInclusive<i64>) -> Result<i64> {
+    for row in range.clone() {
+        let covered: Vec<_> = readings.iter().map(|r| r.row_coverage(row)).collect();
+
+        let mut x: i64 = *range.start();
+        while x <= *range.end() {
+            if let Some(in_range) = covered.iter().find(|r| r.contains(&x)) {
+                x = *in_range.end();
+            } else {
+                return Ok(x * 4000000 + row);
+            }
+            x += 1;
+        }
+    }
+
+    bail!("Could not find gap")
+}
+
+/// The sensors, their beacons and the area they cover, scaled down so that the picture is at
+/// most `size` cells across.
+pub fn draw_coverage(readings: &[Reading], size: i64) -> Picture {
+    let points = readings.iter().flat_map(|r| [r.sensor, r.beacon]);
+    let min_x = points.clone().map(|p| p.x).min().unwrap_or(0);
+    let max_x = points.clone().map(|p| p.x).max().unwrap_or(0);
+    let min_y = points.clone().map(|p| p.y).min().unwrap_or(0);
+    let max_y = points.map(|p| p.y).max().unwrap_or(0);
+    let scale = ((max_x - min_x).max(max_y - min_y) / size + 1).max(1);
+    let cell = |p: Point<i64>| Point::new((p.x - min_x) / scale, (p.y - min_y) / scale);
+
+    let palette = Palette::new([
+        Ink::new([15, 15, 25], '.'),
+        Ink::new([50, 90, 140], '#'),
+        Ink::new([240, 200, 60], 'B'),
+        Ink::new([230, 60, 60], 'S'),
+    ]);
+    let covered = (0..=(max_y - min_y) / scale).flat_map(|y| {
+        (0..=(max_x - min_x) / scale).map(move |x| {
+            let p = Point::new(min_x + x * scale, min_y + y * scale);
+            let covered = readings
+                .iter()
+                .any(|r| r.sensor.manhattan(p) <= r.distance());
+            (Point::new(x, y), covered.into())
+        })
+    });
+    let markers = readings
+        .iter()
+        .flat_map(|r| [(cell(r.beacon), 2), (cell(r.sensor), 3)]);
+    Picture::from_points(covered.chain(markers), palette)
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Reading>;
+
+    fn parse(&self, lines: &[String]) -> Result<Self::Input> {
+        parse_readings(lines).context("reading sensors")
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(row_coverage(input, 2000000, -5000000..=5000000).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(find_gap(input, 0..=4000000).context("finding gap")?.into())
+    }
+
+    fn render(&self, input: &Self::Input) -> Result<Option<Picture>> {
+        Ok(Some(draw_coverage(input, 400)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::fixture;
+    use proptest::prelude::*;
+    #[test]
+    fn test_day15() -> Result<()> {
+        assert_eq!(
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15".parse::<Reading>()?,
+            Reading {
+                sensor: Point::new(2, 18),
+                beacon: Point::new(-2, 15)
+            }
+        );
+        let readings = parse_readings(fixture(15, "example")?)?;
+        assert_eq!(row_coverage(&readings, 10, -200..=200), 26);
+        assert_eq!(find_gap(&readings, 0..=20)?, 56000011);
+        let picture = draw_coverage(&readings, 100);
+        assert_eq!((picture.width(), picture.height()), (28, 23));
+        assert_eq!(picture.to_ascii().matches('S').count(), readings.len());
+
+        Ok(())
+    }
+
+    fn reading() -> impl Strategy<Value = Reading> {
+        let point = || (-20i64..20, -20i64..20).prop_map(|(x, y)| Point::new(x, y));
+        (point(), point()).prop_map(|(sensor, beacon)| Reading { sensor, beacon })
+    }
+
+    proptest! {
+        #[test]
+        fn coverage_matches_brute_force(
+            readings in prop::collection::vec(reading(), 0..6),
+            row in -30i64..30,
+        ) {
+            let range = -60..=60;
+            let expected = range
+                .clone()
+                .map(|x| Point::new(x, row))
+                .filter(|&p| readings.iter().any(|r| r.sensor.manhattan(p) <= r.distance()))
+                .filter(|&p| readings.iter().all(|r| r.beacon != p))
+                .count();
+            prop_assert_eq!(row_coverage(&readings, row, range), expected);
+        }
+    }
+}
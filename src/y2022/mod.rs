@@ -0,0 +1,92 @@
+//! Advent of Code 2022.
+
+use crate::solution::Day;
+
+/// The year these puzzles were set.
+pub const YEAR: u16 = 2022;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// Every solved day of 2022, in order.
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &day01::Day01,
+    },
+    Day {
+        number: 2,
+        solution: &day02::Day02,
+    },
+    Day {
+        number: 3,
+        solution: &day03::Day03,
+    },
+    Day {
+        number: 4,
+        solution: &day04::Day04,
+    },
+    Day {
+        number: 5,
+        solution: &day05::Day05,
+    },
+    Day {
+        number: 6,
+        solution: &day06::Day06,
+    },
+    Day {
+        number: 7,
+        solution: &day07::Day07,
+    },
+    Day {
+        number: 8,
+        solution: &day08::Day08,
+    },
+    Day {
+        number: 9,
+        solution: &day09::Day09,
+    },
+    Day {
+        number: 10,
+        solution: &day10::Day10,
+    },
+    Day {
+        number: 11,
+        solution: &day11::Day11,
+    },
+    Day {
+        number: 12,
+        solution: &day12::Day12,
+    },
+    Day {
+        number: 13,
+        solution: &day13::Day13,
+    },
+    Day {
+        number: 14,
+        solution: &day14::Day14,
+    },
+    Day {
+        number: 15,
+        solution: &day15::Day15,
+    },
+];
+
+/// The example input `name` for `day` of this year, for tests.
+#[cfg(test)]
+pub(crate) fn fixture(day: u8, name: &str) -> anyhow::Result<Vec<String>> {
+    crate::input::fixture(YEAR, day, name)
+}
@@ -0,0 +1,38 @@
+//! Replay the inputs that the fuzz targets in `fuzz/` found crashes with, which are kept as
+//! `fixtures/<year>/dayNN/fuzz-*.txt`. Each must now be solved or rejected with an error, not panic.
+//!
+//! The inputs are replayed through both parts and the drawing, even where the fuzz target for the
+//! day only parses, since each is small and was kept for the panic it found.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+use aoc::runner::solve_text;
+use aoc::{Part, YEARS};
+
+#[test]
+fn fuzz_crashes_are_errors() -> Result<()> {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    for (year, day) in YEARS
+        .iter()
+        .flat_map(|year| year.days.iter().map(move |day| (year.number, day)))
+    {
+        let dir = fixtures
+            .join(year.to_string())
+            .join(format!("day{:02}", day.number));
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let is_crash = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("fuzz-"));
+            if is_crash {
+                // Only a panic fails the test; the input may well be an error.
+                let _ = solve_text(day, &fs::read_to_string(&path)?, &Part::ALL, true);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Check each day against the full puzzle inputs and the answers recorded for them.
+//!
+//! The inputs are not part of the repository, so a day whose input is missing is skipped unless
+//! the `full-inputs` feature is enabled. Inputs are read from the input store in `$AOC_INPUT_DIR`,
+//! or the crate root, falling back to the `<year>/dayNN.txt` files there, or for 2022 the loose
+//! `dayNN.txt` files.
+
+use std::env;
+use std::path::PathBuf;
+
+use anyhow::{ensure, Result};
+
+use aoc::find_day;
+use aoc::input::{InputSource, INPUT_DIR_VAR};
+use aoc::store::{Status, Store};
+use aoc::verify::{verify_day, AnswerSet, Outcome, ANSWERS_FILE};
+
+fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
+
+fn check_day(year: u16, number: u8) -> Result<()> {
+    let dir = input_dir();
+    let source = InputSource::for_day(year, number, Some(&dir))?;
+    let status = Store::in_dir(Some(&dir))?.status(year, number)?;
+    ensure!(
+        !matches!(status, Status::Changed { .. }),
+        "Input {source} for day {number} of {year} has {status}"
+    );
+    if !source.exists() {
+        ensure!(
+            !cfg!(feature = "full-inputs"),
+            "Missing input {source} for day {number} of {year}"
+        );
+        eprintln!("Skipping day {number} of {year}: no input at {source}");
+        return Ok(());
+    }
+
+    let answers = AnswerSet::load(&dir.join(ANSWERS_FILE))?;
+    for check in verify_day(year, find_day(year, number)?, &source, &answers) {
+        ensure!(
+            matches!(check.outcome, Outcome::Pass),
+            "Day {} part {}: {}",
+            check.day,
+            check.part,
+            check.outcome
+        );
+    }
+    Ok(())
+}
+
+macro_rules! regression_tests {
+    ($($name:ident: $year:expr, $day:expr;)*) => {
+        $(
+            #[test]
+            fn $name() -> Result<()> {
+                check_day($year, $day)
+            }
+        )*
+    };
+}
+
+regression_tests! {
+    y2022_day01: 2022, 1;
+    y2022_day02: 2022, 2;
+    y2022_day03: 2022, 3;
+    y2022_day04: 2022, 4;
+    y2022_day05: 2022, 5;
+    y2022_day06: 2022, 6;
+    y2022_day07: 2022, 7;
+    y2022_day08: 2022, 8;
+    y2022_day09: 2022, 9;
+    y2022_day10: 2022, 10;
+    y2022_day11: 2022, 11;
+    y2022_day12: 2022, 12;
+    y2022_day13: 2022, 13;
+    y2022_day14: 2022, 14;
+    y2022_day15: 2022, 15;
+}